@@ -0,0 +1,78 @@
+use crate::Time;
+
+/// Fluent builder for [`Time`].
+///
+/// Unset components default to zero, and [`TimeBuilder::build`] normalizes
+/// the result.
+///
+/// ```
+/// use time_calculator::{Time, TimeBuilder};
+///
+/// let t = TimeBuilder::default()
+///     .with_hour(1)
+///     .with_minute(23)
+///     .with_second(45)
+///     .build();
+/// assert_eq!(t, Time::new(1, 23, 45));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TimeBuilder {
+    hour: u64,
+    minute: u64,
+    second: u64,
+}
+
+impl TimeBuilder {
+    /// Sets the hour component.
+    pub fn with_hour(mut self, hour: u64) -> Self {
+        self.hour = hour;
+        self
+    }
+
+    /// Sets the minute component.
+    pub fn with_minute(mut self, minute: u64) -> Self {
+        self.minute = minute;
+        self
+    }
+
+    /// Sets the second component.
+    pub fn with_second(mut self, second: u64) -> Self {
+        self.second = second;
+        self
+    }
+
+    /// Builds the normalized `Time`, saturating at [`Time::MAX`] like
+    /// [`Time::new`].
+    pub fn build(self) -> Time {
+        Time::new(self.hour, self.minute, self.second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Time, TimeBuilder};
+    use rstest::*;
+    #[rstest]
+    fn test_build() {
+        let t = TimeBuilder::default()
+            .with_hour(1)
+            .with_minute(23)
+            .with_second(45)
+            .build();
+        assert_eq!(t, Time::new(1, 23, 45));
+        assert_eq!(TimeBuilder::default().build(), Time::ZERO);
+        assert_eq!(
+            TimeBuilder::default().with_minute(5).build(),
+            Time::new(0, 5, 0)
+        );
+    }
+    #[rstest]
+    fn test_build_carry() {
+        let t = TimeBuilder::default().with_second(5025).build();
+        assert_eq!(t.to_string(), "01:23:45".to_string());
+        let t = TimeBuilder::default().with_hour(1).with_hour(2).build();
+        assert_eq!(t, Time::new(2, 0, 0));
+        let t = TimeBuilder::default().with_hour(u64::MAX).build();
+        assert_eq!(t, Time::MAX);
+    }
+}
@@ -0,0 +1,73 @@
+use chrono::TimeDelta;
+
+use crate::{Time, TimeError};
+
+/// Converts through whole seconds.
+///
+/// # Panics
+///
+/// Panics if the time exceeds the range of `chrono::Duration`, about
+/// `i64::MAX` milliseconds.
+impl From<Time> for TimeDelta {
+    fn from(time: Time) -> Self {
+        i64::try_from(time.total_seconds())
+            .ok()
+            .and_then(TimeDelta::try_seconds)
+            .expect("`Time` is too large for `chrono::Duration`")
+    }
+}
+
+/// Fails with [`TimeError::Negative`] for negative durations. The sub-second
+/// part is truncated; go through [`TimeDelta::to_std`] and
+/// [`TimeMs`](crate::TimeMs) to keep milliseconds.
+impl TryFrom<TimeDelta> for Time {
+    type Error = TimeError;
+    fn try_from(delta: TimeDelta) -> Result<Self, Self::Error> {
+        if delta < TimeDelta::zero() {
+            return Err(TimeError::Negative);
+        }
+        Ok(Time::from_seconds(delta.num_seconds() as u64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::fixture;
+    use crate::{Time, TimeError};
+    use chrono::Duration;
+    use rstest::*;
+    #[rstest]
+    fn test_into_chrono(fixture: [Time; 2]) {
+        assert_eq!(Duration::from(fixture[0]), Duration::seconds(5025));
+        assert_eq!(Duration::from(fixture[1]), Duration::zero());
+        assert_eq!(Duration::from(Time::new(50, 0, 0)), Duration::hours(50));
+    }
+    #[rstest]
+    fn test_from_chrono(fixture: [Time; 2]) {
+        assert_eq!(Time::try_from(Duration::seconds(5025)), Ok(fixture[0]));
+        assert_eq!(Time::try_from(Duration::zero()), Ok(fixture[1]));
+        assert_eq!(
+            Time::try_from(Duration::milliseconds(5025999)),
+            Ok(fixture[0])
+        );
+        assert_eq!(
+            Time::try_from(Duration::seconds(-1)),
+            Err(TimeError::Negative)
+        );
+        assert_eq!(
+            Time::try_from(Duration::milliseconds(-1)),
+            Err(TimeError::Negative)
+        );
+    }
+    #[rstest]
+    fn test_round_trip(fixture: [Time; 2]) {
+        for t in fixture.into_iter().chain([Time::new(49, 0, 7)]) {
+            assert_eq!(Time::try_from(Duration::from(t)), Ok(t));
+        }
+    }
+    #[rstest]
+    #[should_panic]
+    fn test_too_large() {
+        let _ = Duration::from(Time::MAX);
+    }
+}
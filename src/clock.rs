@@ -0,0 +1,192 @@
+use alloc::string::String;
+use core::fmt::{self, Display, Formatter};
+use core::ops::{Add, Sub};
+
+use crate::Time;
+
+const SECONDS_PER_DAY: u64 = 86400;
+
+/// A 24-hour wall-clock time of day.
+///
+/// Unlike [`Time`], which models durations, a `Clock` wraps around midnight:
+/// arithmetic is performed modulo 24 hours and never goes negative.
+///
+/// ```
+/// use time_calculator::{Clock, Time};
+///
+/// let late = Clock::new(23, 30, 0);
+/// assert_eq!(late.wrapping_add(Time::new(2, 0, 0)), Clock::new(1, 30, 0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Clock {
+    seconds: u64,
+}
+
+impl Clock {
+    /// Creates a `Clock`, wrapping values past midnight.
+    pub fn new(hour: u64, minute: u64, second: u64) -> Self {
+        Clock::from(Time::new(hour, minute, second))
+    }
+
+    /// Returns the time elapsed since midnight.
+    pub fn time(&self) -> Time {
+        Time::from_seconds(self.seconds)
+    }
+
+    /// Formats as a 12-hour time such as `1:23:45 PM`; see [`Time::format_12h`].
+    pub fn format_12h(&self) -> String {
+        self.time().format_12h()
+    }
+
+    /// Moves the clock forward by `time`, wrapping past midnight.
+    pub fn wrapping_add(self, time: Time) -> Clock {
+        Clock {
+            seconds: (self.seconds + time.total_seconds() % SECONDS_PER_DAY) % SECONDS_PER_DAY,
+        }
+    }
+
+    /// Moves the clock back by `time`, wrapping before midnight.
+    pub fn wrapping_sub(self, time: Time) -> Clock {
+        let back = time.total_seconds() % SECONDS_PER_DAY;
+        Clock {
+            seconds: (self.seconds + SECONDS_PER_DAY - back) % SECONDS_PER_DAY,
+        }
+    }
+}
+
+/// Hand angles for drawing an analog clock face.
+///
+/// Angles are in degrees clockwise from 12 o'clock and always lie in
+/// `[0, 360)`. Only the time of day counts, so whole days are ignored.
+impl Time {
+    /// Returns the hour hand angle, which advances smoothly with the minutes
+    /// and seconds: `01:30:00` is 45°, not 30°.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(1, 30, 0).hour_hand_degrees(), 45.0);
+    /// assert_eq!(Time::new(12, 0, 0).hour_hand_degrees(), 0.0);
+    /// ```
+    pub fn hour_hand_degrees(&self) -> f64 {
+        (self.total_seconds() % (SECONDS_PER_DAY / 2)) as f64 / 120.0
+    }
+
+    /// Returns the minute hand angle, which advances smoothly with the seconds.
+    pub fn minute_hand_degrees(&self) -> f64 {
+        (self.total_seconds() % 3600) as f64 / 10.0
+    }
+
+    /// Returns the second hand angle, which ticks once per second.
+    pub fn second_hand_degrees(&self) -> f64 {
+        (self.total_seconds() % 60) as f64 * 6.0
+    }
+}
+
+#[cfg(feature = "std")]
+impl Time {
+    /// Returns the current UTC time of day, read from the system clock.
+    ///
+    /// The result is the time elapsed since midnight UTC, so it is always
+    /// below 24 hours. A system clock set before the Unix epoch reads as
+    /// `00:00:00`.
+    pub fn now() -> Time {
+        let since_epoch = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default();
+        Time::from_seconds(since_epoch.as_secs() % SECONDS_PER_DAY)
+    }
+}
+
+/// Takes the time of day of a duration, discarding whole days.
+impl From<Time> for Clock {
+    fn from(time: Time) -> Self {
+        Clock {
+            seconds: time.total_seconds() % SECONDS_PER_DAY,
+        }
+    }
+}
+
+impl Display for Clock {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.time(), f)
+    }
+}
+
+impl Add<Time> for Clock {
+    type Output = Self;
+    fn add(self, time: Time) -> Clock {
+        self.wrapping_add(time)
+    }
+}
+
+impl Sub<Time> for Clock {
+    type Output = Self;
+    fn sub(self, time: Time) -> Clock {
+        self.wrapping_sub(time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Clock, Time};
+    use rstest::*;
+    #[fixture]
+    pub fn fixture() -> [Clock; 2] {
+        [Clock::new(23, 30, 0), Clock::new(0, 30, 0)]
+    }
+    #[rstest]
+    fn test_new(fixture: [Clock; 2]) {
+        assert_eq!(Clock::new(24, 30, 0), fixture[1]);
+        assert_eq!(Clock::new(0, 0, 86400), Clock::default());
+        assert_eq!(fixture[0].to_string(), "23:30:00".to_string());
+        assert_eq!(format!("{:>10}", fixture[1]), "  00:30:00");
+        assert_eq!(fixture[0].time(), Time::new(23, 30, 0));
+        assert_eq!(Clock::from(Time::new(50, 0, 0)), Clock::new(2, 0, 0));
+    }
+    #[rstest]
+    fn test_format_12h(fixture: [Clock; 2]) {
+        assert_eq!(fixture[0].format_12h(), "11:30:00 PM".to_string());
+        assert_eq!(fixture[1].format_12h(), "12:30:00 AM".to_string());
+    }
+    #[rstest]
+    fn test_wrapping_add(fixture: [Clock; 2]) {
+        let [late, early] = fixture;
+        assert_eq!(late.wrapping_add(Time::new(2, 0, 0)), Clock::new(1, 30, 0));
+        assert_eq!(late.wrapping_add(Time::new(1, 0, 0)), early);
+        assert_eq!(late + Time::new(0, 29, 59), Clock::new(23, 59, 59));
+        assert_eq!(late + Time::new(48, 0, 0), late);
+    }
+    #[rstest]
+    fn test_wrapping_sub(fixture: [Clock; 2]) {
+        let [late, early] = fixture;
+        assert_eq!(early.wrapping_sub(Time::new(1, 0, 0)), late);
+        assert_eq!(early - Time::new(0, 30, 0), Clock::default());
+        assert_eq!(early - Time::new(0, 30, 1), Clock::new(23, 59, 59));
+        assert_eq!(early - Time::new(72, 0, 0), early);
+    }
+    #[rstest]
+    fn test_hand_degrees() {
+        let t = Time::new(1, 30, 0);
+        assert_eq!(t.hour_hand_degrees(), 45.0);
+        assert_eq!(t.minute_hand_degrees(), 180.0);
+        assert_eq!(t.second_hand_degrees(), 0.0);
+        let noon = Time::new(12, 0, 0);
+        assert_eq!(noon.hour_hand_degrees(), 0.0);
+        assert_eq!(noon.minute_hand_degrees(), 0.0);
+        assert_eq!(Time::new(27, 0, 0).hour_hand_degrees(), 90.0);
+        let t = Time::new(9, 15, 45);
+        assert_eq!(t.hour_hand_degrees(), 277.875);
+        assert_eq!(t.minute_hand_degrees(), 94.5);
+        assert_eq!(t.second_hand_degrees(), 270.0);
+        assert_eq!(Time::new(11, 59, 59).second_hand_degrees(), 354.0);
+    }
+    #[cfg(feature = "std")]
+    #[rstest]
+    fn test_now() {
+        let now = Time::now();
+        assert!(now.is_normalized());
+        assert!(now < Time::new(24, 0, 0));
+        assert_eq!(now.day(), 0);
+    }
+}
@@ -0,0 +1,100 @@
+use alloc::string::String;
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// Error returned when a string can't be parsed into a [`Time`](crate::Time).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseTimeError {
+    /// The input was empty.
+    Empty,
+    /// The input didn't have an accepted number of separator-delimited fields.
+    FieldCount(usize),
+    /// A field wasn't a valid non-negative integer.
+    InvalidField(String),
+    /// The fields add up to more than [`Time::MAX`](crate::Time::MAX).
+    Overflow,
+}
+
+impl Display for ParseTimeError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ParseTimeError::Empty => write!(f, "cannot parse time from empty string"),
+            ParseTimeError::FieldCount(n) => {
+                write!(f, "unexpected number of time fields: {}", n)
+            }
+            ParseTimeError::InvalidField(field) => write!(f, "invalid time field `{}`", field),
+            ParseTimeError::Overflow => write!(f, "time is too large to represent"),
+        }
+    }
+}
+
+impl Error for ParseTimeError {}
+
+/// Error returned by the fallible arithmetic and parsing APIs of [`Time`](crate::Time).
+///
+/// Some variants only exist with certain features enabled, so matches need a
+/// wildcard arm.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TimeError {
+    /// A division or remainder by zero was attempted.
+    DivisionByZero,
+    /// The result doesn't fit in the representable range.
+    Overflow,
+    /// A negative span was given, which `Time` can't represent.
+    Negative,
+    /// A minute or second component was 60 or more where exact input was required.
+    FieldOutOfRange,
+    /// An iteration step of `00:00:00` was given, which would never advance.
+    ZeroStep,
+    /// A string couldn't be parsed into a time.
+    Parse(ParseTimeError),
+    /// The given 1-based line of an input couldn't be parsed into a time.
+    Line(usize, ParseTimeError),
+    /// Reading the input failed.
+    ///
+    /// Only the [`ErrorKind`](std::io::ErrorKind) is kept, so that the error
+    /// stays `Clone` and `Eq`; the underlying `io::Error` message is lost.
+    #[cfg(feature = "io")]
+    Io(std::io::ErrorKind),
+}
+
+impl Display for TimeError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            TimeError::DivisionByZero => write!(f, "cannot divide `Time` by zero"),
+            TimeError::Overflow => write!(f, "`Time` arithmetic overflowed"),
+            TimeError::Negative => write!(f, "`Time` cannot be negative"),
+            TimeError::FieldOutOfRange => {
+                write!(f, "minutes and seconds must be below 60")
+            }
+            TimeError::ZeroStep => write!(f, "step must be longer than `00:00:00`"),
+            TimeError::Parse(err) => write!(f, "{}", err),
+            TimeError::Line(line, err) => write!(f, "line {}: {}", line, err),
+            #[cfg(feature = "io")]
+            TimeError::Io(kind) => write!(f, "failed to read times: {}", kind),
+        }
+    }
+}
+
+impl Error for TimeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TimeError::Parse(err) | TimeError::Line(_, err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Wraps the error in [`TimeError::Parse`], except that
+/// [`ParseTimeError::Overflow`] becomes [`TimeError::Overflow`] so every
+/// parser reports an oversized value the same way.
+impl From<ParseTimeError> for TimeError {
+    fn from(err: ParseTimeError) -> Self {
+        match err {
+            ParseTimeError::Overflow => TimeError::Overflow,
+            err => TimeError::Parse(err),
+        }
+    }
+}
@@ -0,0 +1,423 @@
+use crate::Time;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+impl Time {
+    /// Formats as a 12-hour time of day such as `1:23:45 PM`.
+    ///
+    /// The value is treated as a time since midnight, so whole days are dropped first.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(13, 23, 45).format_12h(), "1:23:45 PM");
+    /// assert_eq!(Time::new(0, 0, 0).format_12h(), "12:00:00 AM");
+    /// ```
+    pub fn format_12h(&self) -> String {
+        let of_day = Time::from_seconds(self.total_seconds() % 86400);
+        let suffix = if of_day.hour < 12 { "AM" } else { "PM" };
+        let hour = match of_day.hour % 12 {
+            0 => 12,
+            hour => hour,
+        };
+        format!(
+            "{}:{:02}:{:02} {}",
+            hour, of_day.minute, of_day.second, suffix
+        )
+    }
+
+    /// Formats like `Display`, but with `sep` between the hours, minutes and
+    /// seconds instead of `:`.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(1, 23, 45).to_string_with_sep('.'), "01.23.45");
+    /// ```
+    pub fn to_string_with_sep(&self, sep: char) -> String {
+        let hms = format!(
+            "{:02}{sep}{:02}{sep}{:02}",
+            self.hour,
+            self.minute,
+            self.second,
+            sep = sep
+        );
+        match self.day {
+            0 => hms,
+            1 => format!("1 day {}", hms),
+            day => format!("{} days {}", day, hms),
+        }
+    }
+
+    /// Formats as `HH:MM`, truncating the seconds.
+    ///
+    /// Whole days get the same prefix as in `Display`.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(1, 23, 45).to_hm_string(), "01:23");
+    /// ```
+    pub fn to_hm_string(&self) -> String {
+        let t = self.normalized();
+        let hm = format!("{:02}:{:02}", t.hour, t.minute);
+        match t.day {
+            0 => hm,
+            1 => format!("1 day {}", hm),
+            day => format!("{} days {}", day, hm),
+        }
+    }
+
+    /// Formats as `HH:MM`, rounding to the nearest minute with 30 seconds
+    /// rounding up.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(1, 23, 45).to_hm_string_rounded(), "01:24");
+    /// ```
+    pub fn to_hm_string_rounded(&self) -> String {
+        self.round_to(Time::MINUTE).to_hm_string()
+    }
+
+    /// Formats the total number of seconds for logs, such as `5025s`.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(1, 23, 45).to_total_seconds_string(), "5025s");
+    /// ```
+    pub fn to_total_seconds_string(&self) -> String {
+        format!("{}s", self.total_seconds())
+    }
+
+    /// Formats the number of whole minutes for logs, such as `83m`, dropping
+    /// leftover seconds like [`Time::total_minutes`].
+    pub fn to_total_minutes_string(&self) -> String {
+        format!("{}m", self.total_minutes())
+    }
+
+    /// Renders `self` as progress towards `total`, such as `[####------] 40%`.
+    ///
+    /// The bar is `width` characters wide between the brackets. Both the fill
+    /// and the percentage round down and stop at 100%, so the bar only shows
+    /// complete once `total` is reached. A zero `total` renders as 0%.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// let elapsed = Time::new(0, 24, 0);
+    /// assert_eq!(elapsed.progress_bar(&Time::new(1, 0, 0), 10), "[####------] 40%");
+    /// ```
+    pub fn progress_bar(&self, total: &Time, width: usize) -> String {
+        let total = u128::from(total.total_seconds());
+        let elapsed = u128::from(self.total_seconds()).min(total);
+        let (percent, filled) = match total {
+            0 => (0, 0),
+            _ => (elapsed * 100 / total, elapsed * width as u128 / total),
+        };
+        let filled = filled as usize;
+        format!(
+            "[{}{}] {}%",
+            "#".repeat(filled),
+            "-".repeat(width - filled),
+            percent
+        )
+    }
+
+    /// Formats according to a `strftime`-like `pattern`.
+    ///
+    /// Supported specifiers:
+    ///
+    /// - `%d`: days
+    /// - `%H`, `%M`, `%S`: hour of the day, minute and second, zero-padded to two digits
+    /// - `%k`: hour of the day, space-padded to two characters
+    /// - `%l`: hour of the day, unpadded
+    /// - `%%`: a literal `%`
+    ///
+    /// Unknown specifiers and a trailing lone `%` are copied through verbatim.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(1, 23, 45).format("%Hh%Mm%Ss"), "01h23m45s");
+    /// ```
+    pub fn format(&self, pattern: &str) -> String {
+        let mut output = String::with_capacity(pattern.len());
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                output.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('d') => output.push_str(&self.day.to_string()),
+                Some('H') => output.push_str(&format!("{:02}", self.hour)),
+                Some('M') => output.push_str(&format!("{:02}", self.minute)),
+                Some('S') => output.push_str(&format!("{:02}", self.second)),
+                Some('k') => output.push_str(&format!("{:>2}", self.hour)),
+                Some('l') => output.push_str(&self.hour.to_string()),
+                Some('%') => output.push('%'),
+                Some(other) => {
+                    output.push('%');
+                    output.push(other);
+                }
+                None => output.push('%'),
+            }
+        }
+        output
+    }
+
+    /// Describes the span in prose, e.g. `1 hour 23 minutes 45 seconds`.
+    ///
+    /// Zero components are left out; an empty span is `0 seconds`.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(1, 23, 45).humanize(), "1 hour 23 minutes 45 seconds");
+    /// assert_eq!(Time::new(0, 0, 5).humanize(), "5 seconds");
+    /// ```
+    pub fn humanize(&self) -> String {
+        let parts: Vec<String> = self
+            .nonzero_parts()
+            .into_iter()
+            .map(|(value, unit, _)| match value {
+                1 => format!("1 {}", unit),
+                _ => format!("{} {}s", value, unit),
+            })
+            .collect();
+        if parts.is_empty() {
+            "0 seconds".to_string()
+        } else {
+            parts.join(" ")
+        }
+    }
+
+    /// Compact counterpart of [`Time::humanize`], e.g. `1h 23m 45s`.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(1, 23, 45).humanize_short(), "1h 23m 45s");
+    /// assert_eq!(Time::new(0, 0, 0).humanize_short(), "0s");
+    /// ```
+    pub fn humanize_short(&self) -> String {
+        let parts: Vec<String> = self
+            .nonzero_parts()
+            .into_iter()
+            .map(|(value, _, suffix)| format!("{}{}", value, suffix))
+            .collect();
+        if parts.is_empty() {
+            "0s".to_string()
+        } else {
+            parts.join(" ")
+        }
+    }
+
+    /// Formats as an ISO 8601 duration such as `PT1H23M45S`.
+    ///
+    /// Zero components are left out, whole days use the `D` designator and an
+    /// empty span is `PT0S`.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(1, 23, 45).to_iso8601(), "PT1H23M45S");
+    /// assert_eq!(Time::new(26, 0, 0).to_iso8601(), "P1DT2H");
+    /// ```
+    pub fn to_iso8601(&self) -> String {
+        let t = Time::from_seconds(self.total_seconds());
+        if t.total_seconds() == 0 {
+            return "PT0S".to_string();
+        }
+        let mut output = "P".to_string();
+        if t.day != 0 {
+            output.push_str(&format!("{}D", t.day));
+        }
+        if t.hour != 0 || t.minute != 0 || t.second != 0 {
+            output.push('T');
+            for (value, designator) in [(t.hour, 'H'), (t.minute, 'M'), (t.second, 'S')] {
+                if value != 0 {
+                    output.push_str(&format!("{}{}", value, designator));
+                }
+            }
+        }
+        output
+    }
+
+    // The non-zero components, largest first, with their long and short unit names.
+    fn nonzero_parts(&self) -> Vec<(u64, &'static str, &'static str)> {
+        let t = Time::from_seconds(self.total_seconds());
+        [
+            (t.day, "day", "d"),
+            (t.hour, "hour", "h"),
+            (t.minute, "minute", "m"),
+            (t.second, "second", "s"),
+        ]
+        .into_iter()
+        .filter(|(value, _, _)| *value != 0)
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::fixture;
+    use crate::Time;
+    use rstest::*;
+    #[rstest]
+    fn test_format_12h(fixture: [Time; 2]) {
+        assert_eq!(fixture[1].format_12h(), "12:00:00 AM".to_string());
+        assert_eq!(fixture[0].format_12h(), "1:23:45 AM".to_string());
+        assert_eq!(Time::new(12, 0, 0).format_12h(), "12:00:00 PM".to_string());
+        assert_eq!(Time::new(13, 0, 0).format_12h(), "1:00:00 PM".to_string());
+        assert_eq!(Time::new(23, 59, 0).format_12h(), "11:59:00 PM".to_string());
+        assert_eq!(Time::new(37, 0, 0).format_12h(), "1:00:00 PM".to_string());
+    }
+    #[rstest]
+    fn test_to_string_with_sep(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        assert_eq!(a.to_string_with_sep('.'), "01.23.45".to_string());
+        assert_eq!(a.to_string_with_sep('-'), "01-23-45".to_string());
+        assert_eq!(a.to_string_with_sep(':'), a.to_string());
+        assert_eq!(b.to_string_with_sep('h'), "00h00h00".to_string());
+        assert_eq!(
+            Time::new(50, 0, 0).to_string_with_sep('.'),
+            "2 days 02.00.00".to_string()
+        );
+    }
+    #[rstest]
+    fn test_to_hm_string(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        assert_eq!(a.to_hm_string(), "01:23".to_string());
+        assert_eq!(a.to_hm_string_rounded(), "01:24".to_string());
+        assert_eq!(b.to_hm_string(), "00:00".to_string());
+        assert_eq!(b.to_hm_string_rounded(), "00:00".to_string());
+        assert_eq!(
+            Time::new(0, 0, 29).to_hm_string_rounded(),
+            "00:00".to_string()
+        );
+        assert_eq!(
+            Time::new(0, 0, 30).to_hm_string_rounded(),
+            "00:01".to_string()
+        );
+        assert_eq!(
+            Time::new(23, 59, 30).to_hm_string_rounded(),
+            "1 day 00:00".to_string()
+        );
+        assert_eq!(Time::new(23, 59, 30).to_hm_string(), "23:59".to_string());
+        assert_eq!(
+            Time::MAX.to_hm_string_rounded(),
+            "213503982334601 days 07:00".to_string()
+        );
+        assert_eq!(
+            Time::new(50, 5, 0).to_hm_string(),
+            "2 days 02:05".to_string()
+        );
+    }
+    #[rstest]
+    fn test_to_total_strings(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        assert_eq!(a.to_total_seconds_string(), "5025s".to_string());
+        assert_eq!(a.to_total_minutes_string(), "83m".to_string());
+        assert_eq!(b.to_total_seconds_string(), "0s".to_string());
+        assert_eq!(b.to_total_minutes_string(), "0m".to_string());
+        assert_eq!(
+            Time::new(50, 0, 0).to_total_minutes_string(),
+            "3000m".to_string()
+        );
+        assert_ne!(a.to_total_seconds_string(), a.humanize_short());
+    }
+    #[rstest]
+    fn test_format(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        assert_eq!(a.format("%H:%M:%S"), a.to_string());
+        assert_eq!(a.format("%Hh%Mm%Ss"), "01h23m45s".to_string());
+        assert_eq!(a.format("%k|%l"), " 1|1".to_string());
+        assert_eq!(Time::new(13, 5, 0).format("%k|%l"), "13|13".to_string());
+        assert_eq!(b.format("%H.%M.%S"), "00.00.00".to_string());
+        assert_eq!(Time::new(50, 0, 0).format("%dd %Hh"), "2d 02h".to_string());
+    }
+    #[rstest]
+    fn test_humanize(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        assert_eq!(a.humanize(), "1 hour 23 minutes 45 seconds".to_string());
+        assert_eq!(b.humanize(), "0 seconds".to_string());
+        assert_eq!(Time::new(0, 0, 5).humanize(), "5 seconds".to_string());
+        assert_eq!(
+            Time::new(2, 1, 0).humanize(),
+            "2 hours 1 minute".to_string()
+        );
+        assert_eq!(Time::new(0, 0, 1).humanize(), "1 second".to_string());
+        assert_eq!(
+            Time::new(49, 0, 2).humanize(),
+            "2 days 1 hour 2 seconds".to_string()
+        );
+        assert_eq!(Time::new(24, 0, 0).humanize(), "1 day".to_string());
+    }
+    #[rstest]
+    fn test_humanize_short(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        assert_eq!(a.humanize_short(), "1h 23m 45s".to_string());
+        assert_eq!(b.humanize_short(), "0s".to_string());
+        assert_eq!(
+            Time::new(49, 30, 0).humanize_short(),
+            "2d 1h 30m".to_string()
+        );
+    }
+    #[rstest]
+    fn test_to_iso8601(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        assert_eq!(a.to_iso8601(), "PT1H23M45S".to_string());
+        assert_eq!(b.to_iso8601(), "PT0S".to_string());
+        assert_eq!(Time::new(0, 0, 45).to_iso8601(), "PT45S".to_string());
+        assert_eq!(Time::new(2, 0, 0).to_iso8601(), "PT2H".to_string());
+        assert_eq!(Time::new(1, 0, 5).to_iso8601(), "PT1H5S".to_string());
+        assert_eq!(Time::new(48, 0, 0).to_iso8601(), "P2D".to_string());
+        assert_eq!(Time::new(49, 1, 0).to_iso8601(), "P2DT1H1M".to_string());
+    }
+    #[rstest]
+    fn test_format_literals(fixture: [Time; 2]) {
+        let a = fixture[0];
+        assert_eq!(a.format("100%% at %H"), "100% at 01".to_string());
+        assert_eq!(a.format("%q%M"), "%q23".to_string());
+        assert_eq!(a.format("%S%"), "45%".to_string());
+        assert_eq!(a.format(""), "".to_string());
+    }
+    #[rstest]
+    fn test_progress_bar(fixture: [Time; 2]) {
+        let [a, zero] = fixture;
+        let total = Time::new(1, 0, 0);
+        assert_eq!(
+            Time::new(0, 30, 0).progress_bar(&total, 10),
+            "[#####-----] 50%".to_string()
+        );
+        assert_eq!(
+            Time::new(0, 30, 0).progress_bar(&total, 4),
+            "[##--] 50%".to_string()
+        );
+        assert_eq!(zero.progress_bar(&total, 4), "[----] 0%".to_string());
+        assert_eq!(total.progress_bar(&total, 4), "[####] 100%".to_string());
+        assert_eq!(a.progress_bar(&total, 4), "[####] 100%".to_string());
+        assert_eq!(
+            Time::new(0, 59, 59).progress_bar(&total, 4),
+            "[###-] 99%".to_string()
+        );
+        assert_eq!(a.progress_bar(&zero, 4), "[----] 0%".to_string());
+        assert_eq!(zero.progress_bar(&zero, 0), "[] 0%".to_string());
+        assert_eq!(
+            Time::from_seconds(29).progress_bar(&Time::from_seconds(100), 10),
+            "[##--------] 29%".to_string()
+        );
+        assert_eq!(
+            Time::from_seconds(57).progress_bar(&Time::from_seconds(100), 100),
+            format!("[{}{}] 57%", "#".repeat(57), "-".repeat(43))
+        );
+        assert_eq!(
+            Time::MAX.progress_bar(&Time::MAX, 3),
+            "[###] 100%".to_string()
+        );
+    }
+}
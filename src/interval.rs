@@ -0,0 +1,140 @@
+use alloc::vec::Vec;
+
+use crate::Time;
+
+/// A span between two points measured as [`Time`] offsets, such as a
+/// scheduled slot from `09:00:00` to `10:30:00`.
+///
+/// The interval is half-open: it contains `start` but not `end`, so a slot
+/// ending at `10:00:00` does not conflict with one starting then.
+///
+/// ```
+/// use time_calculator::{Interval, Time};
+///
+/// let slot = Interval::new(Time::new(9, 0, 0), Time::new(10, 30, 0));
+/// assert_eq!(slot.duration(), Time::new(1, 30, 0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Interval {
+    pub start: Time,
+    pub end: Time,
+}
+
+impl Interval {
+    /// Creates an interval from `start` to `end`.
+    pub fn new(start: Time, end: Time) -> Self {
+        Interval { start, end }
+    }
+
+    /// Returns `end - start`, or zero if the interval is reversed.
+    pub fn duration(&self) -> Time {
+        self.end - self.start
+    }
+
+    /// Returns `true` if the two intervals share any instant.
+    ///
+    /// Intervals that merely touch, where one ends as the other starts, do
+    /// not overlap.
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// Sorts `intervals` by start and coalesces the ones that overlap or
+    /// touch, returning the smallest set covering the same spans.
+    ///
+    /// ```
+    /// use time_calculator::{Interval, Time};
+    ///
+    /// let busy = [
+    ///     Interval::new(Time::new(1, 0, 0), Time::new(2, 0, 0)),
+    ///     Interval::new(Time::new(0, 0, 0), Time::new(1, 0, 0)),
+    /// ];
+    /// assert_eq!(
+    ///     Interval::merge(&busy),
+    ///     [Interval::new(Time::new(0, 0, 0), Time::new(2, 0, 0))]
+    /// );
+    /// ```
+    pub fn merge(intervals: &[Interval]) -> Vec<Interval> {
+        let mut sorted = intervals.to_vec();
+        sorted.sort();
+        let mut merged: Vec<Interval> = Vec::with_capacity(sorted.len());
+        for interval in sorted {
+            match merged.last_mut() {
+                Some(last) if interval.start <= last.end => {
+                    last.end = last.end.max(interval.end);
+                }
+                _ => merged.push(interval),
+            }
+        }
+        merged
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Interval, Time};
+    use rstest::*;
+    #[fixture]
+    pub fn fixture() -> [Interval; 2] {
+        [
+            Interval::new(Time::new(1, 0, 0), Time::new(2, 30, 0)),
+            Interval::new(Time::new(2, 0, 0), Time::new(3, 0, 0)),
+        ]
+    }
+    #[rstest]
+    fn test_duration(fixture: [Interval; 2]) {
+        let [a, b] = fixture;
+        assert_eq!(a.duration(), Time::new(1, 30, 0));
+        assert_eq!(a.duration().to_string(), "01:30:00".to_string());
+        assert_eq!(b.duration(), Time::HOUR);
+        assert_eq!(Interval::new(b.end, a.start).duration(), Time::ZERO);
+        assert_eq!(Interval::default().duration(), Time::ZERO);
+    }
+    #[rstest]
+    fn test_overlaps(fixture: [Interval; 2]) {
+        let [a, b] = fixture;
+        assert!(a.overlaps(&b));
+        assert!(b.overlaps(&a));
+        assert!(a.overlaps(&a));
+        let later = Interval::new(Time::new(3, 0, 0), Time::new(4, 0, 0));
+        assert!(!a.overlaps(&later));
+        assert!(!later.overlaps(&a));
+        assert!(!b.overlaps(&later));
+        let inside = Interval::new(Time::new(1, 15, 0), Time::new(1, 45, 0));
+        assert!(a.overlaps(&inside));
+        assert!(inside.overlaps(&a));
+    }
+    #[rstest]
+    fn test_merge(fixture: [Interval; 2]) {
+        let hm = |h, m| Time::new(h, m, 0);
+        let intervals = [
+            Interval::new(hm(0, 0), hm(1, 0)),
+            Interval::new(hm(0, 30), hm(2, 0)),
+            Interval::new(hm(3, 0), hm(4, 0)),
+        ];
+        let expected = [
+            Interval::new(hm(0, 0), hm(2, 0)),
+            Interval::new(hm(3, 0), hm(4, 0)),
+        ];
+        assert_eq!(Interval::merge(&intervals), expected);
+        let mut reversed = intervals;
+        reversed.reverse();
+        assert_eq!(Interval::merge(&reversed), expected);
+        let [a, b] = fixture;
+        assert_eq!(
+            Interval::merge(&[b, a]),
+            [Interval::new(hm(1, 0), hm(3, 0))]
+        );
+        let touching = [
+            Interval::new(hm(1, 0), hm(2, 0)),
+            Interval::new(hm(2, 0), hm(3, 0)),
+        ];
+        assert_eq!(
+            Interval::merge(&touching),
+            [Interval::new(hm(1, 0), hm(3, 0))]
+        );
+        let nested = [a, Interval::new(hm(1, 15), hm(1, 45))];
+        assert_eq!(Interval::merge(&nested), [a]);
+        assert_eq!(Interval::merge(&[]), []);
+    }
+}
@@ -0,0 +1,107 @@
+//! Reading and writing columns of times, one per line.
+
+use std::io::{BufRead, Write};
+
+use crate::{Time, TimeError};
+
+/// Reads one time per line in the form accepted by [`Time`]'s `FromStr`.
+///
+/// Blank lines are skipped. A malformed line stops the read with
+/// [`TimeError::Line`], carrying its 1-based line number.
+///
+/// ```
+/// use time_calculator::{io, Time};
+///
+/// let times = io::read_times("01:23:45\n\n00:00:10\n".as_bytes()).unwrap();
+/// assert_eq!(times, [Time::new(1, 23, 45), Time::new(0, 0, 10)]);
+/// ```
+pub fn read_times<R: BufRead>(reader: R) -> Result<Vec<Time>, TimeError> {
+    let mut times = Vec::new();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line.map_err(|err| TimeError::Io(err.kind()))?;
+        if line.trim_ascii().is_empty() {
+            continue;
+        }
+        let time = line
+            .parse()
+            .map_err(|err| TimeError::Line(index + 1, err))?;
+        times.push(time);
+    }
+    Ok(times)
+}
+
+/// Writes each time on its own line in its `Display` form.
+///
+/// ```
+/// use time_calculator::{io, Time};
+///
+/// let mut out = Vec::new();
+/// io::write_times(&mut out, &[Time::new(1, 23, 45)]).unwrap();
+/// assert_eq!(out, b"01:23:45\n");
+/// ```
+pub fn write_times<W: Write>(mut writer: W, times: &[Time]) -> std::io::Result<()> {
+    for time in times {
+        writeln!(writer, "{}", time)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::{read_times, write_times};
+    use crate::tests::fixture;
+    use crate::{ParseTimeError, Time, TimeError};
+    use rstest::*;
+    use std::io::{self, BufReader, Read};
+    #[rstest]
+    fn test_read_times(fixture: [Time; 2]) {
+        let input = "01:23:45\n00:00:00\r\n\n  \n1 day 00:00:10\n00:00:5025";
+        assert_eq!(
+            read_times(input.as_bytes()),
+            Ok(vec![
+                fixture[0],
+                fixture[1],
+                Time::new(24, 0, 10),
+                fixture[0]
+            ])
+        );
+        assert_eq!(read_times("".as_bytes()), Ok(vec![]));
+    }
+    #[rstest]
+    fn test_read_times_error() {
+        let input = "01:23:45\n\n01:23\n";
+        assert_eq!(
+            read_times(input.as_bytes()),
+            Err(TimeError::Line(3, ParseTimeError::FieldCount(2)))
+        );
+        assert_eq!(
+            read_times("00:00:01\n18446744073709551615:00:00\n".as_bytes()),
+            Err(TimeError::Line(2, ParseTimeError::Overflow))
+        );
+        assert_eq!(
+            TimeError::Line(3, ParseTimeError::FieldCount(2)).to_string(),
+            "line 3: unexpected number of time fields: 2".to_string()
+        );
+        struct Broken;
+        impl Read for Broken {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::ErrorKind::BrokenPipe.into())
+            }
+        }
+        assert_eq!(
+            read_times(BufReader::new(Broken)),
+            Err(TimeError::Io(io::ErrorKind::BrokenPipe))
+        );
+    }
+    #[rstest]
+    fn test_round_trip(fixture: [Time; 2]) {
+        let times = [fixture[0], fixture[1], Time::new(50, 0, 0)];
+        let mut out = Vec::new();
+        write_times(&mut out, &times).unwrap();
+        assert_eq!(
+            String::from_utf8(out.clone()).unwrap(),
+            "01:23:45\n00:00:00\n2 days 02:00:00\n"
+        );
+        assert_eq!(read_times(&out[..]), Ok(times.to_vec()));
+    }
+}
@@ -1,22 +1,982 @@
-use std::fmt::{self, Display, Formatter};
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
-#[derive(Debug, Clone)]
-struct Time {
-    hour: usize,
-    minute: usize,
-    second: usize,
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::{self, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+use core::str::FromStr;
+use core::time::Duration;
+
+mod builder;
+#[cfg(feature = "chrono")]
+mod chrono_impl;
+mod clock;
+mod error;
+mod format;
+mod interval;
+#[cfg(feature = "io")]
+pub mod io;
+mod millis;
+mod parse;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod signed;
+#[cfg(feature = "std")]
+mod stopwatch;
+#[cfg(feature = "time")]
+mod time_impl;
+
+pub use crate::builder::TimeBuilder;
+pub use crate::clock::Clock;
+pub use crate::error::{ParseTimeError, TimeError};
+pub use crate::interval::Interval;
+pub use crate::millis::TimeMs;
+#[cfg(feature = "serde")]
+pub use crate::serde_impl::{serde_as_hms_struct, serde_as_seconds, serde_as_string};
+pub use crate::signed::SignedTime;
+#[cfg(feature = "std")]
+pub use crate::stopwatch::{Stopwatch, Timer};
+
+/// A span of time made up of days, hours, minutes and seconds.
+///
+/// The default value is `00:00:00`. Times are ordered by their total seconds,
+/// so [`Ord::min`] and [`Ord::max`] pick the shorter or longer span and
+/// [`Ord::clamp`] bounds a value into a window:
+///
+/// ```
+/// use time_calculator::Time;
+///
+/// assert_eq!(Time::new(1, 0, 0).max(Time::new(0, 30, 0)), Time::new(1, 0, 0));
+/// assert_eq!(Time::new(1, 0, 0).min(Time::new(0, 30, 0)), Time::new(0, 30, 0));
+///
+/// let (min, max) = (Time::new(0, 10, 0), Time::new(1, 0, 0));
+/// assert_eq!(Time::new(0, 5, 0).clamp(min, max), min);
+/// assert_eq!(Time::new(2, 0, 0).clamp(min, max), max);
+/// assert_eq!(Time::new(0, 30, 0).clamp(min, max), Time::new(0, 30, 0));
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct Time {
+    day: u64,
+    hour: u64,
+    minute: u64,
+    second: u64,
 }
 
+/// Former name of [`Time`], kept so existing code keeps compiling.
+pub use crate::Time as Mytime;
+
 impl Time {
-    fn total_seconds(&self) -> usize {
-        self.hour * 3600 + self.minute * 60 + self.second
+    /// `00:00:00`.
+    pub const ZERO: Time = Time::from_parts(0, 0, 0, 0);
+    /// One second, `00:00:01`.
+    pub const SECOND: Time = Time::from_parts(0, 0, 0, 1);
+    /// One minute, `00:01:00`.
+    pub const MINUTE: Time = Time::from_parts(0, 0, 1, 0);
+    /// One hour, `01:00:00`.
+    pub const HOUR: Time = Time::from_parts(0, 1, 0, 0);
+    /// The shortest representable span, equal to [`Time::ZERO`].
+    pub const MIN: Time = Time::ZERO;
+    /// The longest representable span, `u64::MAX` seconds.
+    ///
+    /// `Time::MAX + Time::SECOND` overflows like integer addition does; use
+    /// [`Time::checked_add`] or [`Time::saturating_add`] near the limit.
+    pub const MAX: Time = Time::from_parts(
+        u64::MAX / 86400,
+        u64::MAX % 86400 / 3600,
+        u64::MAX % 3600 / 60,
+        u64::MAX % 60,
+    );
+
+    // Builds a value from components that are already normalized.
+    const fn from_parts(day: u64, hour: u64, minute: u64, second: u64) -> Self {
+        Time {
+            day,
+            hour,
+            minute,
+            second,
+        }
+    }
+
+    /// Creates a new `Time`, carrying overflowing seconds, minutes and hours upwards.
+    ///
+    /// Components adding up to more than [`Time::MAX`] saturate to it; use
+    /// [`Time::from_hms_strict`] to get an error instead.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(0, 0, 5025).to_string(), "01:23:45");
+    /// assert_eq!(Time::new(u64::MAX, 0, 0), Time::MAX);
+    /// ```
+    pub fn new(hour: u64, minute: u64, second: u64) -> Self {
+        Time::checked_from_parts(0, hour, minute, second).unwrap_or(Time::MAX)
+    }
+
+    /// Creates a `Time`, rejecting components that would need carrying.
+    ///
+    /// Returns [`TimeError::FieldOutOfRange`] if `minute` or `second` is 60 or
+    /// more, instead of carrying it upwards like [`Time::new`]. Hours may
+    /// exceed a day, but a total beyond [`Time::MAX`] gives
+    /// [`TimeError::Overflow`].
+    ///
+    /// ```
+    /// use time_calculator::{Time, TimeError};
+    ///
+    /// assert_eq!(Time::from_hms_strict(1, 23, 45), Ok(Time::new(1, 23, 45)));
+    /// assert_eq!(Time::from_hms_strict(1, 90, 0), Err(TimeError::FieldOutOfRange));
+    /// ```
+    pub fn from_hms_strict(hour: u64, minute: u64, second: u64) -> Result<Time, TimeError> {
+        if minute >= 60 || second >= 60 {
+            return Err(TimeError::FieldOutOfRange);
+        }
+        Time::checked_from_parts(0, hour, minute, second).ok_or(TimeError::Overflow)
+    }
+
+    /// Creates a `Time` from a number of seconds.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::from_seconds(5025), Time::new(1, 23, 45));
+    /// ```
+    pub fn from_seconds(seconds: u64) -> Self {
+        Time::new(0, 0, seconds)
+    }
+
+    /// Creates a `Time` from a number of seconds, returning [`Time::MAX`] if
+    /// `seconds` is too large to represent.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::saturating_from_seconds(5025), Time::new(1, 23, 45));
+    /// assert_eq!(Time::saturating_from_seconds(u128::MAX), Time::MAX);
+    /// ```
+    pub fn saturating_from_seconds(seconds: u128) -> Self {
+        Time::from_seconds(u64::try_from(seconds).unwrap_or(u64::MAX))
+    }
+
+    /// Creates a `Time` from a number of minutes, saturating at [`Time::MAX`].
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::from_minutes(90), Time::new(1, 30, 0));
+    /// ```
+    pub fn from_minutes(minutes: u64) -> Self {
+        Time::new(0, minutes, 0)
+    }
+
+    /// Creates a `Time` from a number of hours, saturating at [`Time::MAX`].
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::from_hours(36).to_string(), "1 day 12:00:00");
+    /// ```
+    pub fn from_hours(hours: u64) -> Self {
+        Time::new(hours, 0, 0)
+    }
+
+    /// Returns the day component.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(50, 0, 0).day(), 2);
+    /// ```
+    pub fn day(&self) -> u64 {
+        self.day
+    }
+
+    /// Returns the hour component, always below 24.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(0, 0, 3661).hour(), 1);
+    /// assert_eq!(Time::new(50, 0, 0).hour(), 2);
+    /// ```
+    pub fn hour(&self) -> u64 {
+        self.hour
+    }
+
+    /// Returns the minute component, always below 60.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(0, 0, 3661).minute(), 1);
+    /// ```
+    pub fn minute(&self) -> u64 {
+        self.minute
+    }
+
+    /// Returns the second component, always below 60.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(0, 0, 3661).second(), 1);
+    /// ```
+    pub fn second(&self) -> u64 {
+        self.second
+    }
+
+    /// Iterates from `start` up to, but excluding, `end` in increments of `step`.
+    ///
+    /// Returns [`TimeError::ZeroStep`] if `step` is zero, since the iterator
+    /// would never advance.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// let ticks: Vec<Time> = Time::step_by(Time::ZERO, Time::MINUTE, Time::SECOND * 15)
+    ///     .unwrap()
+    ///     .collect();
+    /// assert_eq!(ticks.len(), 4);
+    /// ```
+    pub fn step_by(
+        start: Time,
+        end: Time,
+        step: Time,
+    ) -> Result<impl Iterator<Item = Time>, TimeError> {
+        if step.is_zero() {
+            return Err(TimeError::ZeroStep);
+        }
+        Ok(
+            core::iter::successors(Some(start), move |t| t.checked_add(step))
+                .take_while(move |t| *t < end),
+        )
+    }
+
+    /// Counts down from `self` to `00:00:00` in decrements of `step`.
+    ///
+    /// Both ends are included; if `step` doesn't divide `self` evenly, the
+    /// last step is shortened so the countdown still finishes on zero.
+    /// Returns [`TimeError::ZeroStep`] if `step` is zero.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// let ticks: Vec<u64> = Time::from_seconds(10)
+    ///     .countdown(Time::from_seconds(3))
+    ///     .unwrap()
+    ///     .map(|t| t.total_seconds())
+    ///     .collect();
+    /// assert_eq!(ticks, [10, 7, 4, 1, 0]);
+    /// ```
+    pub fn countdown(self, step: Time) -> Result<impl Iterator<Item = Time>, TimeError> {
+        if step.is_zero() {
+            return Err(TimeError::ZeroStep);
+        }
+        Ok(core::iter::successors(Some(self), move |t| {
+            (!t.is_zero()).then(|| *t - step)
+        }))
+    }
+
+    /// Returns `true` if the span is `00:00:00`.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert!(Time::from_seconds(0).is_zero());
+    /// assert!(!Time::SECOND.is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool {
+        self.total_seconds() == 0
+    }
+
+    /// Returns `true` if no component exceeds its range, i.e. hours are below
+    /// 24 and minutes and seconds below 60.
+    ///
+    /// Values built through the public API always are:
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert!(Time::new(0, 90, 5025).is_normalized());
+    /// assert!(Time::from_hours(50).is_normalized());
+    /// ```
+    pub fn is_normalized(&self) -> bool {
+        self.hour < 24 && self.minute < 60 && self.second < 60
+    }
+
+    /// Returns the normalized `(hours, minutes, seconds)`, folding whole days
+    /// into the hours so that the tuple converts back to the same value.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::from((0, 0, 5025)).to_hms(), (1, 23, 45));
+    /// assert_eq!(Time::new(26, 0, 0).to_hms(), (26, 0, 0));
+    /// ```
+    pub fn to_hms(&self) -> (u64, u64, u64) {
+        let t = Time::from_seconds(self.total_seconds());
+        (t.day * 24 + t.hour, t.minute, t.second)
+    }
+
+    /// Breaks the span down into `(weeks, days, hours, minutes, seconds)`.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::from_seconds(8 * 86400 + 3661).to_weeks_days_hms(), (1, 1, 1, 1, 1));
+    /// ```
+    pub fn to_weeks_days_hms(&self) -> (u64, u64, u64, u64, u64) {
+        let t = Time::from_seconds(self.total_seconds());
+        (t.day / 7, t.day % 7, t.hour, t.minute, t.second)
+    }
+
+    /// Returns the whole span expressed in seconds.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(1, 23, 45).total_seconds(), 5025);
+    /// ```
+    pub fn total_seconds(&self) -> u64 {
+        self.day * 86400 + self.hour * 3600 + self.minute * 60 + self.second
+    }
+
+    /// Returns the number of whole minutes in the span, truncating any
+    /// leftover seconds.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(1, 30, 45).total_minutes(), 90);
+    /// ```
+    pub fn total_minutes(&self) -> u64 {
+        self.total_seconds() / 60
+    }
+
+    /// Returns the number of whole hours in the span, truncating the rest.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(25, 59, 59).total_hours(), 25);
+    /// ```
+    pub fn total_hours(&self) -> u64 {
+        self.total_seconds() / 3600
+    }
+
+    /// Returns the span in seconds as an `f64`.
+    pub fn as_secs_f64(&self) -> f64 {
+        self.total_seconds() as f64
+    }
+
+    /// Returns the span in fractional minutes.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(0, 1, 30).as_minutes_f64(), 1.5);
+    /// ```
+    pub fn as_minutes_f64(&self) -> f64 {
+        self.as_secs_f64() / 60.0
+    }
+
+    /// Returns the span in fractional hours.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(1, 30, 0).as_hours_f64(), 1.5);
+    /// ```
+    pub fn as_hours_f64(&self) -> f64 {
+        self.as_secs_f64() / 3600.0
+    }
+
+    /// Returns the ratio `self / other`, or `None` if `other` is zero.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(1, 0, 0).checked_ratio(&Time::new(0, 30, 0)), Some(2.0));
+    /// assert_eq!(Time::new(1, 0, 0).checked_ratio(&Time::ZERO), None);
+    /// ```
+    pub fn checked_ratio(&self, other: &Time) -> Option<f64> {
+        if other.is_zero() {
+            return None;
+        }
+        Some(*self / *other)
+    }
+
+    /// Returns `self` as a percentage of `whole`.
+    ///
+    /// A zero `whole` yields `f64::NAN` rather than an error, so progress
+    /// reporting can check with [`f64::is_nan`] and carry on.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(0, 30, 0).percent_of(Time::new(1, 0, 0)), 50.0);
+    /// assert!(Time::new(0, 30, 0).percent_of(Time::ZERO).is_nan());
+    /// ```
+    pub fn percent_of(&self, whole: Time) -> f64 {
+        if whole.is_zero() {
+            return f64::NAN;
+        }
+        self.as_secs_f64() / whole.as_secs_f64() * 100.0
+    }
+
+    /// Returns the mean of `times`, or `None` if the slice is empty.
+    ///
+    /// The mean is truncated to whole seconds, like integer division.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// let laps = [Time::new(0, 0, 10), Time::new(0, 0, 21)];
+    /// assert_eq!(Time::average(&laps), Some(Time::new(0, 0, 15)));
+    /// assert_eq!(Time::average(&[]), None);
+    /// ```
+    pub fn average(times: &[Time]) -> Option<Time> {
+        if times.is_empty() {
+            return None;
+        }
+        // A mean never exceeds the largest input, so only the sum needs widening.
+        let sum: u128 = times.iter().map(|t| u128::from(t.total_seconds())).sum();
+        Some(Time::from_seconds((sum / times.len() as u128) as u64))
+    }
+
+    /// Adds `other`, returning `None` if the total overflows.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(
+    ///     Time::new(1, 0, 0).checked_add(Time::new(0, 30, 0)),
+    ///     Some(Time::new(1, 30, 0))
+    /// );
+    /// assert_eq!(Time::new(0, 0, u64::MAX).checked_add(Time::new(0, 0, 1)), None);
+    /// ```
+    pub fn checked_add(self, other: Time) -> Option<Time> {
+        let second = self.total_seconds().checked_add(other.total_seconds())?;
+        Some(Time::from_seconds(second))
+    }
+
+    /// Multiplies by `factor`, returning `None` if the total overflows.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(0, 30, 0).checked_mul(3), Some(Time::new(1, 30, 0)));
+    /// assert_eq!(Time::new(u64::MAX / 3600, 0, 0).checked_mul(10), None);
+    /// ```
+    pub fn checked_mul(self, factor: usize) -> Option<Time> {
+        let second = self.total_seconds().checked_mul(factor as u64)?;
+        Some(Time::from_seconds(second))
+    }
+
+    /// Adds `other`, capping the total at `u64::MAX` seconds instead of overflowing.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// let max = Time::new(0, 0, u64::MAX);
+    /// assert_eq!(max.saturating_add(Time::new(1, 0, 0)), max);
+    /// ```
+    pub fn saturating_add(self, other: Time) -> Time {
+        Time::from_seconds(self.total_seconds().saturating_add(other.total_seconds()))
+    }
+
+    /// Subtracts `other`, flooring the result at `00:00:00`.
+    ///
+    /// This is what the `-` operator does; use [`Time::checked_sub`] to find
+    /// out whether the floor was hit.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(
+    ///     Time::new(1, 0, 0).saturating_sub(Time::new(2, 0, 0)),
+    ///     Time::new(0, 0, 0)
+    /// );
+    /// ```
+    pub fn saturating_sub(self, other: Time) -> Time {
+        Time::from_seconds(self.total_seconds().saturating_sub(other.total_seconds()))
+    }
+
+    /// Adds `other`, wrapping around at `u64::MAX` seconds.
+    ///
+    /// The wrap is modulo the integer range, not modulo 24 hours; use
+    /// [`Clock`] for time-of-day arithmetic.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// let max = Time::from_seconds(u64::MAX);
+    /// assert_eq!(max.wrapping_add(Time::new(0, 0, 2)), Time::new(0, 0, 1));
+    /// ```
+    pub fn wrapping_add(self, other: Time) -> Time {
+        Time::from_seconds(self.total_seconds().wrapping_add(other.total_seconds()))
+    }
+
+    /// Multiplies by `factor`, wrapping around at `u64::MAX` seconds.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// let half = Time::from_seconds(u64::MAX / 2 + 1);
+    /// assert_eq!(half.wrapping_mul(2), Time::ZERO);
+    /// ```
+    pub fn wrapping_mul(self, factor: usize) -> Time {
+        Time::from_seconds(self.total_seconds().wrapping_mul(factor as u64))
+    }
+
+    /// Returns the absolute difference between `self` and `other` regardless of order.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// let (a, b) = (Time::new(1, 0, 0), Time::new(0, 20, 0));
+    /// assert_eq!(a.abs_diff(b), Time::new(0, 40, 0));
+    /// assert_eq!(b.abs_diff(a), Time::new(0, 40, 0));
+    /// ```
+    pub fn abs_diff(self, other: Time) -> Time {
+        Time::from_seconds(self.total_seconds().abs_diff(other.total_seconds()))
+    }
+
+    /// Returns the absolute difference between `a` and `b` regardless of order.
+    ///
+    /// The result is symmetric, so the arguments may be given in timeline
+    /// order for readability; this is [`Time::abs_diff`] under another name.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// let (start, end) = (Time::new(0, 20, 0), Time::new(1, 0, 0));
+    /// assert_eq!(Time::duration_between(start, end), Time::new(0, 40, 0));
+    /// ```
+    pub fn duration_between(a: Time, b: Time) -> Time {
+        a.abs_diff(b)
+    }
+
+    /// Returns `true` if `self` and `other` differ by at most `tolerance`.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// let (a, b) = (Time::new(0, 0, 10), Time::new(0, 0, 12));
+    /// assert!(a.approx_eq(&b, Time::new(0, 0, 5)));
+    /// assert!(!a.approx_eq(&b, Time::new(0, 0, 1)));
+    /// ```
+    pub fn approx_eq(&self, other: &Time, tolerance: Time) -> bool {
+        self.abs_diff(*other) <= tolerance
+    }
+
+    /// Returns the time halfway between `self` and `other`.
+    ///
+    /// Odd sums are truncated to the whole second below. The half-sum is
+    /// computed without overflowing, even for the largest values.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(0, 0, 0).midpoint(Time::new(0, 0, 10)), Time::new(0, 0, 5));
+    /// assert_eq!(Time::new(0, 0, 0).midpoint(Time::new(0, 0, 11)), Time::new(0, 0, 5));
+    /// ```
+    pub fn midpoint(self, other: Time) -> Time {
+        let (a, b) = (self.total_seconds(), other.total_seconds());
+        Time::from_seconds(a / 2 + b / 2 + (a % 2 + b % 2) / 2)
+    }
+
+    /// Interpolates linearly from `a` towards `b`, rounding to the nearest second.
+    ///
+    /// `t` is clamped to `[0, 1]`, so the result always lies between `a` and
+    /// `b`; a NaN `t` yields `a`. `b` may be earlier than `a`.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// let (a, b) = (Time::new(0, 0, 0), Time::new(0, 0, 100));
+    /// assert_eq!(Time::lerp(a, b, 0.25), Time::new(0, 0, 25));
+    /// assert_eq!(Time::lerp(b, a, 0.25), Time::new(0, 0, 75));
+    /// ```
+    pub fn lerp(a: Time, b: Time, t: f64) -> Time {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let (a, b) = (a.total_seconds(), b.total_seconds());
+        let distance = a.abs_diff(b);
+        // `f64` rounding may overshoot for distances beyond 2^53 seconds.
+        let step = round_to_u64(distance as f64 * t).min(distance);
+        if b >= a {
+            Time::from_seconds(a + step)
+        } else {
+            Time::from_seconds(a - step)
+        }
+    }
+
+    /// Divides by `other`, returning an error instead of panicking on zero.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert!(Time::new(1, 0, 0).checked_div(0).is_err());
+    /// assert_eq!(Time::new(1, 0, 0).checked_div(4), Ok(Time::new(0, 15, 0)));
+    /// ```
+    pub fn checked_div(self, other: usize) -> Result<Time, TimeError> {
+        if other == 0 {
+            return Err(TimeError::DivisionByZero);
+        }
+        Ok(Time::from_seconds(self.total_seconds() / other as u64))
+    }
+
+    /// In-place counterpart of [`Time::checked_mul`], returning
+    /// [`TimeError::Overflow`] and leaving `self` untouched if the product
+    /// doesn't fit.
+    ///
+    /// ```
+    /// use time_calculator::{Time, TimeError};
+    ///
+    /// let mut t = Time::MAX;
+    /// assert_eq!(t.checked_mul_assign(2), Err(TimeError::Overflow));
+    /// assert_eq!(t, Time::MAX);
+    /// ```
+    pub fn checked_mul_assign(&mut self, factor: usize) -> Result<(), TimeError> {
+        *self = self.checked_mul(factor).ok_or(TimeError::Overflow)?;
+        Ok(())
     }
-    fn normalize(&mut self) {
-        self.minute += self.second / 60;
+
+    /// In-place counterpart of [`Time::checked_div`]; `self` is left untouched on error.
+    pub fn checked_div_assign(&mut self, other: usize) -> Result<(), TimeError> {
+        *self = self.checked_div(other)?;
+        Ok(())
+    }
+
+    /// Scales by the exact fraction `num / den`, truncating to whole seconds.
+    ///
+    /// The product is computed in `u128`, so `self * num` may exceed
+    /// [`Time::MAX`] as long as the final result doesn't.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `den` is zero, like division does, or if the result is
+    /// larger than [`Time::MAX`].
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(1, 0, 0).scale_ratio(3, 4), Time::new(0, 45, 0));
+    /// ```
+    pub fn scale_ratio(self, num: usize, den: usize) -> Time {
+        if den == 0 {
+            panic!("Cannot divide by zero-valued `Time`!");
+        }
+        let scaled = self.total_seconds() as u128 * num as u128 / den as u128;
+        Time::from_seconds(u64::try_from(scaled).expect("`Time::scale_ratio` overflowed"))
+    }
+
+    /// Returns `(self / divisor, self % divisor)` in one call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero, like the `/` and `%` operators.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// let (share, left) = Time::new(0, 0, 100).div_rem(30);
+    /// assert_eq!(share, Time::new(0, 0, 3));
+    /// assert_eq!(left, Time::new(0, 0, 10));
+    /// ```
+    pub fn div_rem(self, divisor: usize) -> (Time, Time) {
+        if divisor == 0 {
+            panic!("Cannot divide by zero-valued `Time`!");
+        }
+        let s = self.total_seconds();
+        let d = divisor as u64;
+        (Time::from_seconds(s / d), Time::from_seconds(s % d))
+    }
+
+    /// Splits the span into `n` parts that sum exactly to `self`.
+    ///
+    /// The seconds that don't divide evenly are handed out one at a time to
+    /// the first parts, so no two parts differ by more than a second. A zero
+    /// `n` returns [`TimeError::DivisionByZero`].
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// let parts = Time::from_seconds(100).split_even(3).unwrap();
+    /// assert_eq!(parts, [34, 33, 33].map(Time::from_seconds));
+    /// ```
+    pub fn split_even(self, n: usize) -> Result<Vec<Time>, TimeError> {
+        if n == 0 {
+            return Err(TimeError::DivisionByZero);
+        }
+        let s = self.total_seconds();
+        let (share, left) = (s / n as u64, s % n as u64);
+        Ok((0..n as u64)
+            .map(|i| Time::from_seconds(share + u64::from(i < left)))
+            .collect())
+    }
+
+    /// Returns the seconds left over after dividing by `other`, or an error on zero.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(0, 0, 100).checked_rem(30), Ok(Time::new(0, 0, 10)));
+    /// assert!(Time::new(0, 0, 100).checked_rem(0).is_err());
+    /// ```
+    pub fn checked_rem(self, other: usize) -> Result<Time, TimeError> {
+        if other == 0 {
+            return Err(TimeError::DivisionByZero);
+        }
+        Ok(Time::from_seconds(self.total_seconds() % other as u64))
+    }
+
+    /// Subtracts `other`, returning `None` if it is larger than `self`.
+    ///
+    /// Unlike [`Time::saturating_sub`], an underflow is reported rather than
+    /// clamped to zero.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(0, 20, 0).checked_sub(Time::new(1, 0, 0)), None);
+    /// assert_eq!(
+    ///     Time::new(1, 0, 0).checked_sub(Time::new(0, 20, 0)),
+    ///     Some(Time::new(0, 40, 0))
+    /// );
+    /// ```
+    pub fn checked_sub(self, other: Time) -> Option<Time> {
+        let second = self.total_seconds().checked_sub(other.total_seconds())?;
+        Some(Time::from_seconds(second))
+    }
+
+    /// Rounds to the nearest multiple of `step`, with ties rounding up.
+    ///
+    /// Rounding up past the largest multiple saturates at [`Time::MAX`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero, like division does.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// let quarter = Time::new(0, 15, 0);
+    /// assert_eq!(Time::new(0, 7, 29).round_to(quarter), Time::ZERO);
+    /// assert_eq!(Time::new(0, 7, 30).round_to(quarter), quarter);
+    /// ```
+    pub fn round_to(self, step: Time) -> Time {
+        let step = step.step_seconds();
+        let rem = self.total_seconds() % step;
+        if rem >= step - rem {
+            Time::from_seconds((self.total_seconds() - rem).saturating_add(step))
+        } else {
+            Time::from_seconds(self.total_seconds() - rem)
+        }
+    }
+
+    /// Rounds down to a multiple of `step`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero, like division does.
+    pub fn floor_to(self, step: Time) -> Time {
+        let step = step.step_seconds();
+        Time::from_seconds(self.total_seconds() - self.total_seconds() % step)
+    }
+
+    /// Rounds up to a multiple of `step`, saturating at [`Time::MAX`] when
+    /// the next multiple is too large.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero, like division does.
+    pub fn ceil_to(self, step: Time) -> Time {
+        let step = step.step_seconds();
+        match self.total_seconds() % step {
+            0 => self,
+            rem => Time::from_seconds((self.total_seconds() - rem).saturating_add(step)),
+        }
+    }
+
+    /// Drops the seconds, rounding down to the whole minute.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(1, 23, 45).floor_to_minute(), Time::new(1, 23, 0));
+    /// ```
+    pub fn floor_to_minute(&self) -> Time {
+        self.floor_to(Time::MINUTE)
+    }
+
+    /// Drops the minutes and seconds, rounding down to the whole hour.
+    pub fn floor_to_hour(&self) -> Time {
+        self.floor_to(Time::HOUR)
+    }
+
+    /// Rounds up to the next whole minute, unless already on one, stopping
+    /// at [`Time::MAX`].
+    pub fn ceil_to_minute(&self) -> Time {
+        self.ceil_to(Time::MINUTE)
+    }
+
+    /// Rounds up to the next whole hour, unless already on one, stopping at
+    /// [`Time::MAX`].
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::new(1, 23, 45).ceil_to_hour(), Time::new(2, 0, 0));
+    /// ```
+    pub fn ceil_to_hour(&self) -> Time {
+        self.ceil_to(Time::HOUR)
+    }
+
+    fn step_seconds(&self) -> u64 {
+        match self.total_seconds() {
+            0 => panic!("Cannot divide by zero-valued `Time`!"),
+            step => step,
+        }
+    }
+
+    /// Adds `seconds` in place, carrying into the larger units.
+    ///
+    /// Like [`Time::saturating_add`], the result stops at [`Time::MAX`]
+    /// rather than overflowing.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// let mut t = Time::new(0, 59, 30);
+    /// t.add_seconds(45);
+    /// assert_eq!(t, Time::new(1, 0, 15));
+    /// ```
+    pub fn add_seconds(&mut self, seconds: u64) {
+        *self = self.saturating_add(Time::from_seconds(seconds));
+    }
+
+    /// Adds `minutes` in place, carrying into the larger units and stopping
+    /// at [`Time::MAX`].
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// let mut t = Time::ZERO;
+    /// t.add_minutes(90);
+    /// assert_eq!(t, Time::new(1, 30, 0));
+    /// ```
+    pub fn add_minutes(&mut self, minutes: u64) {
+        self.add_seconds(minutes.saturating_mul(60));
+    }
+
+    /// Adds `hours` in place, carrying into days and stopping at [`Time::MAX`].
+    pub fn add_hours(&mut self, hours: u64) {
+        self.add_seconds(hours.saturating_mul(3600));
+    }
+
+    /// Subtracts `seconds` in place, stopping at `00:00:00` like `-=` does.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// let mut t = Time::new(0, 1, 0);
+    /// t.sub_seconds(120);
+    /// assert_eq!(t, Time::ZERO);
+    /// ```
+    pub fn sub_seconds(&mut self, seconds: u64) {
+        *self -= Time::from_seconds(seconds);
+    }
+
+    /// Subtracts `minutes` in place, stopping at `00:00:00`.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// let mut t = Time::new(1, 0, 0);
+    /// t.sub_minutes(30);
+    /// assert_eq!(t, Time::new(0, 30, 0));
+    /// ```
+    pub fn sub_minutes(&mut self, minutes: u64) {
+        self.sub_seconds(minutes.saturating_mul(60));
+    }
+
+    /// Subtracts `hours` in place, stopping at `00:00:00`.
+    pub fn sub_hours(&mut self, hours: u64) {
+        self.sub_seconds(hours.saturating_mul(3600));
+    }
+
+    /// Carries overflowing seconds, minutes and hours upwards in place,
+    /// returning `self` for chaining.
+    ///
+    /// Hours are capped below 24, with the excess carried into days. The
+    /// operation is idempotent: normalizing twice is the same as normalizing
+    /// once.
+    ///
+    /// The components must add up to at most [`Time::MAX`]. Debug builds
+    /// assert this; release builds saturate the carries instead, leaving a
+    /// value whose total is meaningless.
+    pub fn normalize(&mut self) -> &mut Self {
+        debug_assert!(
+            self.checked_total_seconds().is_some(),
+            "`Time` components overflow `u64` seconds"
+        );
+        self.minute = self.minute.saturating_add(self.second / 60);
         self.second %= 60;
-        self.hour += self.minute / 60;
+        self.hour = self.hour.saturating_add(self.minute / 60);
         self.minute %= 60;
+        self.day = self.day.saturating_add(self.hour / 24);
+        self.hour %= 24;
+        self
+    }
+
+    // Normalizes raw components, or returns `None` if they add up to more
+    // than `Time::MAX`, so parsers can reject oversized input.
+    fn checked_from_parts(day: u64, hour: u64, minute: u64, second: u64) -> Option<Time> {
+        let output = Time::from_parts(day, hour, minute, second);
+        output.checked_total_seconds()?;
+        Some(output.normalized())
+    }
+
+    fn checked_total_seconds(&self) -> Option<u64> {
+        self.day
+            .checked_mul(86400)?
+            .checked_add(self.hour.checked_mul(3600)?)?
+            .checked_add(self.minute.checked_mul(60)?)?
+            .checked_add(self.second)
+    }
+
+    /// Returns a normalized copy, leaving `self` untouched.
+    pub fn normalized(&self) -> Time {
+        let mut output = *self;
+        output.normalize();
+        output
+    }
+
+    /// Normalizes every value in `times` in place.
+    ///
+    /// The public constructors, parsers and operators already return
+    /// normalized values, so this is only needed for values whose components
+    /// were assembled inside the crate.
+    pub fn normalize_all(times: &mut [Time]) {
+        for time in times {
+            time.normalize();
+        }
+    }
+}
+
+// Rounds half away from zero with the saturating semantics of an `f64` to
+// `u64` cast. `f64::round` lives in `std`, so this also works without it.
+fn round_to_u64(x: f64) -> u64 {
+    let truncated = x as u64;
+    if x - truncated as f64 >= 0.5 {
+        truncated.saturating_add(1)
+    } else {
+        truncated
     }
 }
 
@@ -26,144 +986,378 @@ impl PartialEq for Time {
     }
 }
 
+impl Eq for Time {}
+
+impl Hash for Time {
+    // Hashes the total so that equal but unnormalized values collide, as `Eq` requires.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.total_seconds().hash(state);
+    }
+}
+
+impl PartialOrd for Time {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Time {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total_seconds().cmp(&other.total_seconds())
+    }
+}
+
+/// Compares the total number of seconds, not the hour field, so
+/// `Time::new(1, 23, 45) == 5025`.
+impl PartialEq<usize> for Time {
+    fn eq(&self, other: &usize) -> bool {
+        self.total_seconds() == *other as u64
+    }
+}
+
+impl PartialEq<Time> for usize {
+    fn eq(&self, other: &Time) -> bool {
+        other == self
+    }
+}
+
+/// Orders against a number of seconds, so `elapsed > 3600` means "more
+/// than an hour".
+impl PartialOrd<usize> for Time {
+    fn partial_cmp(&self, other: &usize) -> Option<Ordering> {
+        Some(self.total_seconds().cmp(&(*other as u64)))
+    }
+}
+
+impl PartialOrd<Time> for usize {
+    fn partial_cmp(&self, other: &Time) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+/// Renders as `HH:MM:SS`, prefixed with `1 day ` or `N days ` once the span reaches a day.
+///
+/// The alternate form `{:#}` leaves the hour unpadded, as in `1:23:45`;
+/// minutes and seconds keep two digits.
+///
+/// Width, fill and alignment apply to the whole rendering, so
+/// `{:>12}` right-aligns a time in a table column.
 impl Display for Time {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+        let days = match self.day {
+            0 => String::new(),
+            1 => "1 day ".to_string(),
+            day => format!("{} days ", day),
+        };
+        let hms = if f.alternate() {
+            format!("{}:{:02}:{:02}", self.hour, self.minute, self.second)
+        } else {
+            format!("{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+        };
+        f.pad(&(days + &hms))
+    }
+}
+
+/// Renders as `Time(01:23:45)`, wrapping the `Display` form.
+impl fmt::Debug for Time {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Time({})", self)
+    }
+}
+
+impl FromStr for Time {
+    type Err = ParseTimeError;
+
+    /// Parses the `[N day[s] ]HH:MM:SS` form produced by `Display`, normalizing the result.
+    ///
+    /// Leading and trailing ASCII whitespace, such as the newline at the end
+    /// of a line read from a file, is ignored. Inside the value only the
+    /// single spaces around `day`/`days` are accepted, so `01 : 23 : 45` is
+    /// rejected.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_separated(s, ':')
+    }
+}
+
+// Parses `[N day[s] ]HH<sep>MM<sep>SS`, the shape shared by `FromStr` and
+// `Time::parse_with_sep`.
+fn parse_separated(s: &str, sep: char) -> Result<Time, ParseTimeError> {
+    let s = s.trim_ascii();
+    if s.is_empty() {
+        return Err(ParseTimeError::Empty);
+    }
+    let tokens: Vec<&str> = s.split(' ').collect();
+    let (day, hms) = match tokens[..] {
+        [hms] => (0, hms),
+        [day, "day" | "days", hms] => (
+            u64::from_str(day).map_err(|_| ParseTimeError::InvalidField(day.to_string()))?,
+            hms,
+        ),
+        _ => return Err(ParseTimeError::InvalidField(s.to_string())),
+    };
+    let fields: Vec<&str> = hms.split(sep).collect();
+    if fields.len() != 3 {
+        return Err(ParseTimeError::FieldCount(fields.len()));
+    }
+    let mut parsed = [0; 3];
+    for (value, field) in parsed.iter_mut().zip(&fields) {
+        *value =
+            u64::from_str(field).map_err(|_| ParseTimeError::InvalidField(field.to_string()))?;
+    }
+    Time::checked_from_parts(day, parsed[0], parsed[1], parsed[2]).ok_or(ParseTimeError::Overflow)
+}
+
+/// Builds a normalized `Time` from `(hours, minutes, seconds)`.
+impl From<(u64, u64, u64)> for Time {
+    fn from((hour, minute, second): (u64, u64, u64)) -> Self {
+        Time::new(hour, minute, second)
+    }
+}
+
+/// Builds a `Time` from a count of seconds.
+///
+/// `Time` holds any `u64` number of seconds, so this only fails with
+/// [`TimeError::Overflow`] on targets where `usize` is wider than 64 bits.
+impl TryFrom<usize> for Time {
+    type Error = TimeError;
+    fn try_from(seconds: usize) -> Result<Self, Self::Error> {
+        u64::try_from(seconds)
+            .map(Time::from_seconds)
+            .map_err(|_| TimeError::Overflow)
+    }
+}
+
+/// Builds a `Time` from a count of seconds, failing with
+/// [`TimeError::Overflow`] above `u64::MAX`.
+impl TryFrom<u128> for Time {
+    type Error = TimeError;
+    fn try_from(seconds: u128) -> Result<Self, Self::Error> {
+        u64::try_from(seconds)
+            .map(Time::from_seconds)
+            .map_err(|_| TimeError::Overflow)
+    }
+}
+
+/// Converts through whole seconds; the sub-second part of the `Duration` is
+/// truncated. Convert into [`TimeMs`] to keep milliseconds.
+impl From<Duration> for Time {
+    fn from(duration: Duration) -> Self {
+        Time::from_seconds(duration.as_secs())
+    }
+}
+
+impl From<Time> for Duration {
+    fn from(time: Time) -> Self {
+        Duration::from_secs(time.total_seconds())
     }
 }
 
 impl Add for Time {
     type Output = Self;
     fn add(self, other: Self) -> Time {
-        let mut output: Time = Time {
-            hour: 0,
-            minute: 0,
-            second: self.total_seconds() + other.total_seconds(),
-        };
-        output.normalize();
-        output
+        Time::from_seconds(self.total_seconds() + other.total_seconds())
+    }
+}
+
+impl Add<&Time> for &Time {
+    type Output = Time;
+    fn add(self, other: &Time) -> Time {
+        *self + *other
     }
 }
 
 impl AddAssign for Time {
     fn add_assign(&mut self, other: Self) {
-        self.second += other.total_seconds();
-        self.normalize();
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<&Time> for Time {
+    fn add_assign(&mut self, other: &Time) {
+        *self += *other;
+    }
+}
+
+impl Sum for Time {
+    fn sum<I: Iterator<Item = Time>>(iter: I) -> Time {
+        Time::from_seconds(iter.map(|t| t.total_seconds()).sum())
+    }
+}
+
+impl<'a> Sum<&'a Time> for Time {
+    fn sum<I: Iterator<Item = &'a Time>>(iter: I) -> Time {
+        Time::from_seconds(iter.map(|t| t.total_seconds()).sum())
+    }
+}
+
+/// Collects by summing, the same as [`Iterator::sum`].
+impl FromIterator<Time> for Time {
+    fn from_iter<I: IntoIterator<Item = Time>>(iter: I) -> Time {
+        iter.into_iter().sum()
+    }
+}
+
+impl<'a> FromIterator<&'a Time> for Time {
+    fn from_iter<I: IntoIterator<Item = &'a Time>>(iter: I) -> Time {
+        iter.into_iter().sum()
+    }
+}
+
+/// Adds every element onto the existing value.
+impl Extend<Time> for Time {
+    fn extend<I: IntoIterator<Item = Time>>(&mut self, iter: I) {
+        *self += iter.into_iter().sum::<Time>();
     }
 }
 
+impl<'a> Extend<&'a Time> for Time {
+    fn extend<I: IntoIterator<Item = &'a Time>>(&mut self, iter: I) {
+        *self += iter.into_iter().sum::<Time>();
+    }
+}
+
+/// Subtraction saturates at `00:00:00` when `other` is larger than `self`.
+/// Use [`Time::checked_sub`] to detect the underflow instead.
 impl Sub for Time {
     type Output = Self;
     fn sub(self, other: Self) -> Time {
-        let s = self.total_seconds();
-        let o = other.total_seconds();
-        let mut output: Time;
-        if s >= o {
-            output = Time {
-                hour: 0,
-                minute: 0,
-                second: s - o,
-            };
-        } else {
-            output = Time {
-                hour: 0,
-                minute: 0,
-                second: o - s,
-            };
-        }
-        output.normalize();
-        output
+        Time::from_seconds(self.total_seconds().saturating_sub(other.total_seconds()))
+    }
+}
+
+impl Sub<&Time> for &Time {
+    type Output = Time;
+    fn sub(self, other: &Time) -> Time {
+        *self - *other
     }
 }
 
 impl SubAssign for Time {
     fn sub_assign(&mut self, other: Self) {
-        let s = self.total_seconds();
-        let o = other.total_seconds();
-        if s >= o {
-            self.hour = 0;
-            self.minute = 0;
-            self.second = s - o;
-        } else {
-            self.hour = 0;
-            self.minute = 0;
-            self.second = o - s;
-        }
-        self.normalize();
+        *self = *self - other;
+    }
+}
+
+impl SubAssign<&Time> for Time {
+    fn sub_assign(&mut self, other: &Time) {
+        *self -= *other;
     }
 }
 
 impl Mul<usize> for Time {
     type Output = Self;
     fn mul(self, int: usize) -> Self {
-        let mut output: Time = Time {
-            hour: 0,
-            minute: 0,
-            second: self.total_seconds() * int,
-        };
-        output.normalize();
-        output
+        Time::from_seconds(self.total_seconds() * int as u64)
+    }
+}
+
+impl Mul<usize> for &Time {
+    type Output = Time;
+    fn mul(self, int: usize) -> Time {
+        *self * int
+    }
+}
+
+impl Mul<Time> for usize {
+    type Output = Time;
+    fn mul(self, time: Time) -> Time {
+        time * self
+    }
+}
+
+/// Scales by a floating-point factor, rounding to the nearest whole second.
+///
+/// The result saturates like an `f64` to `u64` cast: negative factors and NaN
+/// give `00:00:00`, and results past the range (including infinity) give
+/// `u64::MAX` seconds.
+impl Mul<f64> for Time {
+    type Output = Self;
+    fn mul(self, factor: f64) -> Self {
+        Time::from_seconds(round_to_u64(self.total_seconds() as f64 * factor))
+    }
+}
+
+impl Mul<Time> for f64 {
+    type Output = Time;
+    fn mul(self, time: Time) -> Time {
+        time * self
     }
 }
 
 impl MulAssign<usize> for Time {
     fn mul_assign(&mut self, other: usize) {
-        self.hour *= other;
-        self.minute *= other;
-        self.second *= other;
-        self.normalize();
+        *self = *self * other;
     }
 }
 
 impl Div<usize> for Time {
     type Output = Self;
     fn div(self, other: usize) -> Self {
-        if other == 0 {
-            panic!("Cannot divide by zero-valued `Time`!");
-        }
-        let mut output: Time = Time {
-            hour: 0,
-            minute: 0,
-            second: self.total_seconds() / other,
-        };
-        output.normalize();
-        output
+        self.checked_div(other)
+            .unwrap_or_else(|_| panic!("Cannot divide by zero-valued `Time`!"))
+    }
+}
+
+impl Div<usize> for &Time {
+    type Output = Time;
+    fn div(self, other: usize) -> Time {
+        *self / other
     }
 }
 
+/// Returns the ratio of the two spans.
+///
+/// The ratio is an `f64` (it used to be an `f32`), so it stays exact for
+/// totals far beyond the `2^24` seconds an `f32` can represent. A zero
+/// divisor yields infinity or NaN; [`Time::checked_ratio`] reports it instead.
 impl Div for Time {
-    type Output = f32;
+    type Output = f64;
     fn div(self, other: Self) -> Self::Output {
-        self.total_seconds() as f32 / other.total_seconds() as f32
+        self.as_secs_f64() / other.as_secs_f64()
     }
 }
 
 impl DivAssign<usize> for Time {
     fn div_assign(&mut self, other: usize) {
-        if other == 0 {
-            panic!("Cannot divide by zero-valued `Time`!");
-        }
-        let s = self.total_seconds();
-        self.hour = 0;
-        self.minute = 0;
-        self.second = s / other;
-        self.normalize();
+        self.checked_div_assign(other)
+            .unwrap_or_else(|_| panic!("Cannot divide by zero-valued `Time`!"));
+    }
+}
+
+impl Rem<usize> for Time {
+    type Output = Self;
+    fn rem(self, other: usize) -> Self {
+        self.checked_rem(other)
+            .unwrap_or_else(|_| panic!("Cannot divide by zero-valued `Time`!"))
+    }
+}
+
+impl RemAssign<usize> for Time {
+    fn rem_assign(&mut self, other: usize) {
+        *self = *self % other;
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::Time;
+    use crate::{ParseTimeError, SignedTime, Time, TimeError};
+    use proptest::prelude::{any, prop_assert, prop_assert_eq, proptest, Strategy};
     use rstest::*;
+    use std::cmp::Ordering;
+    use std::collections::{BTreeSet, HashMap, HashSet};
+    use std::time::Duration;
+    // Shared by the test modules of the other files as well.
     #[fixture]
-    pub fn fixture() -> [Time; 2] {
+    pub(crate) fn fixture() -> [Time; 2] {
         [
             Time {
+                day: 0,
                 hour: 1,
                 minute: 23,
                 second: 45,
             },
             Time {
+                day: 0,
                 hour: 0,
                 minute: 0,
                 second: 0,
@@ -182,6 +1376,64 @@ mod tests {
         assert_eq!(b.second, 0);
     }
     #[rstest]
+    fn test_new(fixture: [Time; 2]) {
+        let a = Time::new(1, 23, 45);
+        assert_eq!(a.to_string(), fixture[0].to_string());
+        let b = Time::new(0, 0, 5025);
+        assert_eq!(b.to_string(), "01:23:45".to_string());
+        let c = Time::new(0, 0, 0);
+        assert_eq!(c.to_string(), fixture[1].to_string());
+        assert_eq!(Time::new(u64::MAX, u64::MAX, u64::MAX), Time::MAX);
+        assert_eq!(Time::new(0, 0, u64::MAX), Time::MAX);
+    }
+    #[rstest]
+    fn test_from_units(fixture: [Time; 2]) {
+        assert_eq!(Time::from_seconds(5025).to_string(), fixture[0].to_string());
+        assert_eq!(Time::from_seconds(0).to_string(), fixture[1].to_string());
+        assert_eq!(Time::from_minutes(90).to_string(), "01:30:00".to_string());
+        assert_eq!(Time::from_minutes(90), Time::new(1, 30, 0));
+        assert_eq!(Time::from_hours(2).to_string(), "02:00:00".to_string());
+        assert_eq!(Time::from_hours(2), Time::from_minutes(120));
+        assert_eq!(Time::from_minutes(u64::MAX), Time::MAX);
+        assert_eq!(Time::from_hours(u64::MAX), Time::MAX);
+        assert_eq!(
+            Time::from_minutes(u64::MAX / 60),
+            Time::from_seconds(u64::MAX / 60 * 60)
+        );
+    }
+    #[rstest]
+    fn test_default(fixture: [Time; 2]) {
+        assert_eq!(Time::default(), Time::from_seconds(0));
+        assert_eq!(Time::default(), fixture[1]);
+        assert_eq!(Time::default().to_string(), "00:00:00".to_string());
+    }
+    #[rstest]
+    fn test_constants(fixture: [Time; 2]) {
+        assert_eq!(Time::ZERO, fixture[1]);
+        assert_eq!(Time::ZERO.total_seconds(), 0);
+        assert_eq!(Time::SECOND.total_seconds(), 1);
+        assert_eq!(Time::MINUTE.total_seconds(), 60);
+        assert_eq!(Time::HOUR.total_seconds(), 3600);
+        assert_eq!(Time::HOUR.to_string(), "01:00:00".to_string());
+        assert_eq!(Time::MINUTE * 60, Time::HOUR);
+        assert_eq!(Time::SECOND * 60, Time::MINUTE);
+        assert_eq!(
+            Time::HOUR + Time::MINUTE * 23 + Time::SECOND * 45,
+            fixture[0]
+        );
+    }
+    #[rstest]
+    fn test_getters(fixture: [Time; 2]) {
+        let a = &fixture[0];
+        assert_eq!(a.hour(), 1);
+        assert_eq!(a.minute(), 23);
+        assert_eq!(a.second(), 45);
+        let b = fixture[1] + Time::new(0, 0, 7384);
+        assert_eq!(b.hour(), 2);
+        assert_eq!(b.minute(), 3);
+        assert_eq!(b.second(), 4);
+    }
+    #[rstest]
     fn test_total_seconds(fixture: [Time; 2]) {
         let a = &fixture[0];
         let b = &fixture[1];
@@ -189,9 +1441,40 @@ mod tests {
         assert_eq!(b.total_seconds(), 0);
     }
     #[rstest]
+    fn test_total_minutes_hours(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        assert_eq!(Time::new(1, 30, 45).total_minutes(), 90);
+        assert_eq!(Time::new(0, 1, 59).total_minutes(), 1);
+        assert_eq!(a.total_minutes(), 83);
+        assert_eq!(a.total_hours(), 1);
+        assert_eq!(Time::new(0, 59, 59).total_hours(), 0);
+        assert_eq!(Time::new(50, 0, 0).total_hours(), 50);
+        assert_eq!(b.total_minutes(), 0);
+        assert_eq!(b.total_hours(), 0);
+    }
+    #[rstest]
+    fn test_as_f64(fixture: [Time; 2]) {
+        assert_eq!(fixture[0].as_secs_f64(), 5025.0);
+        assert_eq!(fixture[1].as_secs_f64(), 0.0);
+        assert_eq!(Time::new(1, 30, 0).as_hours_f64(), 1.5);
+        assert_eq!(Time::new(0, 45, 0).as_hours_f64(), 0.75);
+        assert_eq!(Time::new(0, 0, 15).as_minutes_f64(), 0.25);
+        assert_eq!(Time::new(36, 0, 0).as_hours_f64(), 36.0);
+    }
+    #[rstest]
+    fn test_percent_of(fixture: [Time; 2]) {
+        let [a, zero] = fixture;
+        assert_eq!(Time::new(0, 30, 0).percent_of(Time::new(1, 0, 0)), 50.0);
+        assert_eq!(a.percent_of(a), 100.0);
+        assert_eq!(zero.percent_of(a), 0.0);
+        assert_eq!(Time::new(2, 0, 0).percent_of(Time::new(1, 0, 0)), 200.0);
+        assert!(a.percent_of(zero).is_nan());
+        assert!(zero.percent_of(zero).is_nan());
+    }
+    #[rstest]
     fn test_eq(fixture: [Time; 2]) {
-        let a = fixture[0].clone();
-        let mut b = fixture[1].clone();
+        let a = fixture[0];
+        let mut b = fixture[1];
         assert_ne!(a, b);
         b.hour = 1;
         assert_ne!(a, b);
@@ -201,6 +1484,87 @@ mod tests {
         assert_eq!(a, b);
     }
     #[rstest]
+    fn test_eq_usize(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        assert!(Time::new(1, 23, 45) == 5025usize);
+        assert!(5025usize == a);
+        assert!(a != 1usize);
+        assert!(b == 0usize);
+        assert_eq!(Time::new(50, 0, 0), 180000usize);
+        let mut unnormalized = b;
+        unnormalized.second = 5025;
+        assert_eq!(unnormalized, 5025usize);
+    }
+    #[rstest]
+    fn test_ord_usize(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        assert!(Time::new(0, 30, 0) < 3600usize);
+        assert!(Time::new(2, 0, 0) > 3600usize);
+        assert!(Time::HOUR >= 3600usize);
+        assert!(Time::HOUR <= 3600usize);
+        assert!(3600usize > Time::new(0, 30, 0));
+        assert!(3600usize < Time::new(2, 0, 0));
+        assert!(a > 5024usize && a < 5026usize);
+        assert!(b <= 0usize);
+        assert_eq!(a.partial_cmp(&5025usize), Some(Ordering::Equal));
+        assert_eq!(5000usize.partial_cmp(&a), Some(Ordering::Less));
+    }
+    #[rstest]
+    fn test_ord(fixture: [Time; 2]) {
+        let a = fixture[0];
+        let mut b = fixture[1];
+        assert!(b < a);
+        assert!(a > b);
+        b.second = a.total_seconds();
+        assert_eq!(a.cmp(&b), Ordering::Equal);
+        assert!(a <= b);
+        assert!(a >= b);
+        let mut v = vec![Time::new(2, 0, 0), a, fixture[1]];
+        v.sort();
+        assert_eq!(v, vec![fixture[1], a, Time::new(2, 0, 0)]);
+        let set: BTreeSet<Time> = v.into_iter().chain([b]).collect();
+        assert_eq!(set.len(), 3);
+    }
+    #[rstest]
+    fn test_min_max(fixture: [Time; 2]) {
+        let a = Time::new(1, 0, 0);
+        let b = Time::new(0, 30, 0);
+        assert_eq!(a.max(b), a);
+        assert_eq!(a.min(b), b);
+        assert_eq!(fixture[0].min(fixture[1]), fixture[1]);
+        let mut unnormalized = fixture[1];
+        unnormalized.second = 7200;
+        assert_eq!(unnormalized.max(a).to_string(), "00:00:7200".to_string());
+        assert_eq!(a.min(unnormalized), a);
+    }
+    #[rstest]
+    fn test_clamp(fixture: [Time; 2]) {
+        let min = Time::new(0, 10, 0);
+        let max = Time::new(1, 0, 0);
+        assert_eq!(fixture[1].clamp(min, max), min);
+        assert_eq!(fixture[0].clamp(min, max), max);
+        assert_eq!(Time::new(0, 30, 0).clamp(min, max), Time::new(0, 30, 0));
+        let mut unnormalized = fixture[1];
+        unnormalized.second = 7200;
+        assert_eq!(unnormalized.clamp(min, max), max);
+    }
+    #[rstest]
+    #[should_panic]
+    fn test_clamp_panic(fixture: [Time; 2]) {
+        let _ = fixture[0].clamp(Time::HOUR, Time::MINUTE);
+    }
+    #[rstest]
+    fn test_hash(fixture: [Time; 2]) {
+        let a = fixture[0];
+        let mut b = fixture[1];
+        b.second = a.total_seconds();
+        let set: HashSet<Time> = [a, b].into_iter().collect();
+        assert_eq!(set.len(), 1);
+        let mut map = HashMap::new();
+        map.insert(a, "lap");
+        assert_eq!(map.get(&Time::new(0, 0, 5025)), Some(&"lap"));
+    }
+    #[rstest]
     fn test_str(fixture: [Time; 2]) {
         let a = &fixture[0];
         let b = &fixture[1];
@@ -208,9 +1572,98 @@ mod tests {
         assert_eq!(b.to_string(), "00:00:00".to_string());
     }
     #[rstest]
+    fn test_str_alternate(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        assert_eq!(format!("{}", a), "01:23:45");
+        assert_eq!(format!("{:#}", a), "1:23:45");
+        assert_eq!(format!("{:#}", b), "0:00:00");
+        assert_eq!(format!("{:#}", Time::new(12, 5, 7)), "12:05:07");
+        assert_eq!(format!("{:#}", Time::new(25, 0, 0)), "1 day 1:00:00");
+    }
+    #[rstest]
+    fn test_debug(fixture: [Time; 2]) {
+        let [a, mut b] = fixture;
+        assert_eq!(format!("{:?}", a), "Time(01:23:45)");
+        assert_eq!(
+            format!("{:?}", Time::new(50, 0, 0)),
+            "Time(2 days 02:00:00)"
+        );
+        assert_eq!(format!("{:?}", Some(a)), "Some(Time(01:23:45))");
+        b.second = 5025;
+        assert_eq!(format!("{:?}", b), "Time(00:00:5025)");
+    }
+    #[rstest]
+    fn test_str_width(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        assert_eq!(format!("{:>12}", Time::new(1, 23, 45)), "    01:23:45");
+        assert_eq!(format!("{:<10}|", a), "01:23:45  |");
+        assert_eq!(format!("{:*^12}", b), "**00:00:00**");
+        assert_eq!(format!("{:>#10}", a), "   1:23:45");
+        assert_eq!(format!("{:4}", a), "01:23:45");
+        assert_eq!(format!("{:>16}", Time::new(25, 0, 0)), "  1 day 01:00:00");
+    }
+    #[rstest]
+    fn test_from_str(fixture: [Time; 2]) {
+        let a: Time = "01:23:45".parse().unwrap();
+        assert_eq!(a, Time::new(1, 23, 45));
+        assert_eq!(a.to_string(), fixture[0].to_string());
+        let b: Time = "00:00:5025".parse().unwrap();
+        assert_eq!(b.to_string(), "01:23:45".to_string());
+        let c: Time = fixture[1].to_string().parse().unwrap();
+        assert_eq!(c, fixture[1]);
+    }
+    #[rstest]
+    fn test_from_str_error() {
+        assert_eq!("".parse::<Time>(), Err(ParseTimeError::Empty));
+        assert_eq!("01:23".parse::<Time>(), Err(ParseTimeError::FieldCount(2)));
+        assert_eq!(
+            "01:23:45:67".parse::<Time>(),
+            Err(ParseTimeError::FieldCount(4))
+        );
+        assert_eq!(
+            "01:xx:45".parse::<Time>(),
+            Err(ParseTimeError::InvalidField("xx".to_string()))
+        );
+        assert_eq!(
+            "01::45".parse::<Time>(),
+            Err(ParseTimeError::InvalidField("".to_string()))
+        );
+        assert_eq!(
+            "-1:23:45".parse::<Time>(),
+            Err(ParseTimeError::InvalidField("-1".to_string()))
+        );
+        assert_eq!(
+            "18446744073709551615:00:00".parse::<Time>(),
+            Err(ParseTimeError::Overflow)
+        );
+        assert_eq!(
+            "213503982334601 days 07:00:16".parse::<Time>(),
+            Err(ParseTimeError::Overflow)
+        );
+        assert_eq!(
+            "213503982334601 days 07:00:15".parse::<Time>(),
+            Ok(Time::MAX)
+        );
+    }
+    #[rstest]
+    fn test_from_str_whitespace(fixture: [Time; 2]) {
+        assert_eq!(" 01:23:45\n".parse::<Time>(), Ok(fixture[0]));
+        assert_eq!("\t00:00:00\r\n".parse::<Time>(), Ok(fixture[1]));
+        assert_eq!("  1 day 01:00:00 ".parse::<Time>(), Ok(Time::new(25, 0, 0)));
+        assert_eq!(" \n".parse::<Time>(), Err(ParseTimeError::Empty));
+        assert_eq!(
+            "01 : 23 : 45".parse::<Time>(),
+            Err(ParseTimeError::InvalidField("01 : 23 : 45".to_string()))
+        );
+        assert_eq!(
+            "01: 23:45".parse::<Time>(),
+            Err(ParseTimeError::InvalidField("01: 23:45".to_string()))
+        );
+    }
+    #[rstest]
     fn test_normalize(fixture: [Time; 2]) {
-        let a = fixture[0].clone();
-        let mut b = fixture[1].clone();
+        let a = fixture[0];
+        let mut b = fixture[1];
         b.second = a.total_seconds();
         assert_eq!(b.to_string(), "00:00:5025".to_string());
         assert_ne!(b.to_string(), a.to_string());
@@ -219,20 +1672,429 @@ mod tests {
         assert_eq!(b.to_string(), a.to_string());
     }
     #[rstest]
+    fn test_add_units(fixture: [Time; 2]) {
+        let [mut a, mut b] = fixture;
+        b.add_minutes(90);
+        assert_eq!(b.to_string(), "01:30:00".to_string());
+        b.add_seconds(1800);
+        assert_eq!(b, Time::new(2, 0, 0));
+        b.add_hours(23);
+        assert_eq!(b.to_string(), "1 day 01:00:00".to_string());
+        a.add_seconds(15);
+        assert_eq!(a.to_string(), "01:24:00".to_string());
+        a.add_minutes(36);
+        assert_eq!(a.to_string(), "02:00:00".to_string());
+        a.add_seconds(0);
+        assert_eq!(a, Time::new(2, 0, 0));
+        let mut c = Time::new(23, 59, 59);
+        c.add_seconds(1);
+        assert_eq!(c.to_string(), "1 day 00:00:00".to_string());
+        let mut d = Time::MAX;
+        d.add_seconds(1);
+        assert_eq!(d, Time::MAX);
+        let mut d = Time::HOUR;
+        d.add_minutes(u64::MAX);
+        assert_eq!(d, Time::MAX);
+        let mut d = Time::ZERO;
+        d.add_hours(u64::MAX);
+        assert_eq!(d, Time::MAX);
+    }
+    #[rstest]
+    fn test_sub_units(fixture: [Time; 2]) {
+        let [mut a, zero] = fixture;
+        let mut t = Time::new(0, 1, 0);
+        t.sub_seconds(120);
+        assert_eq!(t, Time::ZERO);
+        let mut t = Time::new(1, 0, 0);
+        t.sub_minutes(30);
+        assert_eq!(t.to_string(), "00:30:00".to_string());
+        a.sub_seconds(45);
+        assert_eq!(a.to_string(), "01:23:00".to_string());
+        a.sub_minutes(23);
+        assert_eq!(a, Time::HOUR);
+        a.sub_hours(2);
+        assert_eq!(a, zero);
+        let mut day = Time::new(25, 0, 0);
+        day.sub_hours(2);
+        assert_eq!(day.to_string(), "23:00:00".to_string());
+        day.sub_minutes(u64::MAX);
+        assert_eq!(day, zero);
+    }
+    #[rstest]
+    fn test_normalize_idempotent(fixture: [Time; 2]) {
+        let fields = |t: &Time| (t.day, t.hour, t.minute, t.second);
+        let raw = [
+            (0, 0, 0, 0),
+            (0, 0, 0, 5025),
+            (0, 0, 59, 60),
+            (0, 23, 59, 60),
+            (0, 24, 0, 0),
+            (1, 47, 120, 7200),
+            (3, 100, 1000, 10000),
+            (0, 0, 0, u64::MAX),
+            (0, u64::MAX / 3600, 0, 0),
+        ];
+        for (day, hour, minute, second) in raw {
+            let mut t = Time {
+                day,
+                hour,
+                minute,
+                second,
+            };
+            let total = t.total_seconds();
+            let once = fields(t.normalize());
+            let twice = fields(t.normalize());
+            assert_eq!(once, twice, "{:?}", (day, hour, minute, second));
+            assert!(t.is_normalized());
+            assert!(t.hour < 24);
+            assert_eq!(t.total_seconds(), total);
+        }
+        for t in fixture {
+            assert_eq!(
+                fields(&t.normalized().normalized()),
+                fields(&t.normalized())
+            );
+        }
+    }
+    #[rstest]
+    fn test_normalize_near_max() {
+        let mut t = Time {
+            day: 0,
+            hour: 0,
+            minute: 0,
+            second: u64::MAX,
+        };
+        t.normalize();
+        assert_eq!(t, Time::MAX);
+        assert_eq!(Time::new(0, u64::MAX / 60, u64::MAX % 60), Time::MAX);
+    }
+    #[cfg(debug_assertions)]
+    #[rstest]
+    #[should_panic(expected = "`Time` components overflow `u64` seconds")]
+    fn test_normalize_overflow() {
+        let mut t = Time::from_parts(0, u64::MAX, 0, 0);
+        t.normalize();
+    }
+    #[rstest]
+    fn test_days(fixture: [Time; 2]) {
+        let a = Time::new(0, 0, 200000);
+        assert_eq!(a.day(), 2);
+        assert_eq!(a.hour(), 7);
+        assert_eq!(a.total_seconds(), 200000);
+        assert_eq!(a.to_string(), "2 days 07:33:20".to_string());
+        assert_eq!(
+            Time::new(24, 0, 0).to_string(),
+            "1 day 00:00:00".to_string()
+        );
+        assert_eq!(Time::new(23, 59, 59).to_string(), "23:59:59".to_string());
+        assert_eq!((a + fixture[0]).to_string(), "2 days 08:57:05".to_string());
+        let mut b = a;
+        b *= 2;
+        assert_eq!(b.to_string(), "4 days 15:06:40".to_string());
+        b -= a;
+        assert_eq!(b, a);
+        assert_eq!("2 days 07:33:20".parse::<Time>(), Ok(a));
+        assert_eq!("1 day 00:00:00".parse::<Time>(), Ok(Time::new(24, 0, 0)));
+        assert_eq!("0 days 48:00:00".parse::<Time>(), Ok(Time::new(48, 0, 0)));
+        assert_eq!(
+            "2 weeks 00:00:00".parse::<Time>(),
+            Err(ParseTimeError::InvalidField("2 weeks 00:00:00".to_string()))
+        );
+        assert_eq!(
+            "x days 00:00:00".parse::<Time>(),
+            Err(ParseTimeError::InvalidField("x".to_string()))
+        );
+    }
+    #[rstest]
+    fn test_to_weeks_days_hms(fixture: [Time; 2]) {
+        assert_eq!(
+            Time::from_seconds(8 * 86400 + 3661).to_weeks_days_hms(),
+            (1, 1, 1, 1, 1)
+        );
+        assert_eq!(Time::new(7 * 24, 0, 0).to_weeks_days_hms(), (1, 0, 0, 0, 0));
+        assert_eq!(Time::new(50, 0, 0).to_weeks_days_hms(), (0, 2, 2, 0, 0));
+        assert_eq!(fixture[0].to_weeks_days_hms(), (0, 0, 1, 23, 45));
+        assert_eq!(fixture[1].to_weeks_days_hms(), (0, 0, 0, 0, 0));
+    }
+    #[rstest]
+    fn test_tuple(fixture: [Time; 2]) {
+        assert_eq!(Time::from((1, 23, 45)), fixture[0]);
+        assert_eq!(Time::from((1, 23, 45)).to_hms(), (1, 23, 45));
+        assert_eq!(Time::from((0, 0, 5025)).to_hms(), (1, 23, 45));
+        assert_eq!(fixture[1].to_hms(), (0, 0, 0));
+        let t: Time = (50, 0, 0).into();
+        assert_eq!(t.to_hms(), (50, 0, 0));
+        assert_eq!(Time::from(t.to_hms()), t);
+        let mut unnormalized = fixture[1];
+        unnormalized.second = 5025;
+        assert_eq!(unnormalized.to_hms(), (1, 23, 45));
+    }
+    #[rstest]
+    fn test_min_max_constants(fixture: [Time; 2]) {
+        assert_eq!(Time::MIN, Time::ZERO);
+        assert_eq!(Time::MIN, fixture[1]);
+        assert_eq!(Time::MAX.total_seconds(), u64::MAX);
+        assert_eq!(Time::MAX.checked_add(Time::SECOND), None);
+        assert_eq!(Time::MAX.saturating_add(Time::SECOND), Time::MAX);
+        assert_eq!(Time::MIN.checked_sub(Time::SECOND), None);
+        assert_eq!(fixture[0].clamp(Time::MIN, Time::MAX), fixture[0]);
+        assert!(Time::MIN < fixture[0] && fixture[0] < Time::MAX);
+    }
+    #[rstest]
+    fn test_from_hms_strict(fixture: [Time; 2]) {
+        assert_eq!(Time::from_hms_strict(1, 23, 45), Ok(fixture[0]));
+        assert_eq!(Time::from_hms_strict(0, 0, 0), Ok(fixture[1]));
+        assert_eq!(Time::from_hms_strict(0, 59, 59), Ok(Time::new(0, 59, 59)));
+        assert_eq!(Time::from_hms_strict(48, 0, 0), Ok(Time::new(48, 0, 0)));
+        assert_eq!(
+            Time::from_hms_strict(1, 90, 0),
+            Err(TimeError::FieldOutOfRange)
+        );
+        assert_eq!(
+            Time::from_hms_strict(0, 0, 60),
+            Err(TimeError::FieldOutOfRange)
+        );
+        assert_eq!(
+            Time::from_hms_strict(0, 60, 60),
+            Err(TimeError::FieldOutOfRange)
+        );
+        assert_eq!(
+            Time::from_hms_strict(u64::MAX, 0, 0),
+            Err(TimeError::Overflow)
+        );
+        assert_eq!(
+            Time::from_hms_strict(u64::MAX / 3600, 0, 0),
+            Ok(Time::from_seconds(u64::MAX / 3600 * 3600))
+        );
+    }
+    #[rstest]
+    fn test_saturating_from_seconds(fixture: [Time; 2]) {
+        assert_eq!(Time::saturating_from_seconds(5025), fixture[0]);
+        assert_eq!(Time::saturating_from_seconds(0), fixture[1]);
+        assert_eq!(Time::saturating_from_seconds(u64::MAX as u128), Time::MAX);
+        assert_eq!(
+            Time::saturating_from_seconds(u64::MAX as u128 + 1),
+            Time::MAX
+        );
+        assert_eq!(Time::saturating_from_seconds(u128::MAX), Time::MAX);
+        assert_eq!(Time::MAX, Time::from_seconds(u64::MAX));
+        assert!(Time::MAX.is_normalized());
+    }
+    #[rstest]
+    fn test_try_from(fixture: [Time; 2]) {
+        assert_eq!(Time::try_from(5025usize), Ok(fixture[0]));
+        assert_eq!(Time::try_from(0usize), Ok(fixture[1]));
+        assert_eq!(Time::try_from(5025u128), Ok(fixture[0]));
+        assert_eq!(
+            Time::try_from(u64::MAX as u128),
+            Ok(Time::from_seconds(u64::MAX))
+        );
+        assert_eq!(
+            Time::try_from(u64::MAX as u128 + 1),
+            Err(TimeError::Overflow)
+        );
+        let t: Result<Time, _> = 90000usize.try_into();
+        assert_eq!(t.unwrap().to_string(), "1 day 01:00:00".to_string());
+    }
+    #[rstest]
+    fn test_duration(fixture: [Time; 2]) {
+        let a = Time::from(Duration::from_secs(5025));
+        assert_eq!(a, fixture[0]);
+        assert_eq!(Duration::from(a), Duration::from_secs(5025));
+        assert_eq!(Time::from(Duration::from_millis(5025999)), fixture[0]);
+        let d: Duration = fixture[1].into();
+        assert_eq!(d, Duration::ZERO);
+    }
+    #[rstest]
+    fn test_predicates(fixture: [Time; 2]) {
+        let [a, mut b] = fixture;
+        assert!(Time::from_seconds(0).is_zero());
+        assert!(b.is_zero());
+        assert!(!a.is_zero());
+        assert!(a.is_normalized());
+        assert!(b.is_normalized());
+        b.second = 60;
+        assert!(!b.is_zero());
+        assert!(!b.is_normalized());
+        b.normalize();
+        assert!(b.is_normalized());
+        b.hour = 24;
+        assert!(!b.is_normalized());
+        assert!(Time::new(0, 0, 200000).is_normalized());
+    }
+    #[rstest]
+    fn test_normalize_all(fixture: [Time; 2]) {
+        let raw = |hour, minute, second| Time {
+            day: 0,
+            hour,
+            minute,
+            second,
+        };
+        let mut times = [raw(0, 0, 5025), raw(25, 61, 61), raw(0, 0, 0), fixture[0]];
+        Time::normalize_all(&mut times);
+        assert_eq!(
+            times.map(|t| (t.day(), t.hour(), t.minute(), t.second())),
+            [(0, 1, 23, 45), (1, 2, 2, 1), (0, 0, 0, 0), (0, 1, 23, 45)]
+        );
+        assert!(times.iter().all(Time::is_normalized));
+        Time::normalize_all(&mut []);
+    }
+    #[rstest]
+    fn test_normalized(fixture: [Time; 2]) {
+        let mut b = fixture[1];
+        b.second = 5025;
+        let c = b.normalized();
+        assert_eq!(c.to_string(), "01:23:45".to_string());
+        assert_eq!(b.to_string(), "00:00:5025".to_string());
+        assert!(b.normalize().is_normalized());
+        assert_eq!(b.to_string(), c.to_string());
+        assert_eq!(fixture[0].normalized().to_string(), fixture[0].to_string());
+    }
+    #[rstest]
+    fn test_floor_ceil_units(fixture: [Time; 2]) {
+        let [a, zero] = fixture;
+        assert_eq!(a.floor_to_minute().to_string(), "01:23:00".to_string());
+        assert_eq!(a.floor_to_hour(), Time::new(1, 0, 0));
+        assert_eq!(a.ceil_to_minute(), Time::new(1, 24, 0));
+        assert_eq!(a.ceil_to_hour().to_string(), "02:00:00".to_string());
+        let exact = Time::new(3, 0, 0);
+        assert_eq!(exact.floor_to_hour(), exact);
+        assert_eq!(exact.ceil_to_hour(), exact);
+        assert_eq!(exact.ceil_to_minute(), exact);
+        assert_eq!(
+            Time::new(23, 59, 1).ceil_to_hour().to_string(),
+            "1 day 00:00:00"
+        );
+        assert_eq!(zero.floor_to_minute(), zero);
+        assert_eq!(zero.ceil_to_hour(), zero);
+        assert_eq!(Time::MAX.ceil_to_minute(), Time::MAX);
+        assert_eq!(Time::MAX.ceil_to_hour(), Time::MAX);
+        assert_eq!(
+            Time::MAX.floor_to_hour().ceil_to_hour(),
+            Time::MAX.floor_to_hour()
+        );
+    }
+    #[rstest]
+    fn test_countdown(fixture: [Time; 2]) {
+        let [a, zero] = fixture;
+        let ticks: Vec<Time> = Time::from_seconds(10)
+            .countdown(Time::from_seconds(3))
+            .unwrap()
+            .collect();
+        assert_eq!(ticks, [10, 7, 4, 1, 0].map(Time::from_seconds));
+        let ticks: Vec<Time> = Time::MINUTE.countdown(Time::SECOND * 20).unwrap().collect();
+        assert_eq!(ticks, [60, 40, 20, 0].map(Time::from_seconds));
+        assert_eq!(
+            zero.countdown(Time::SECOND).unwrap().collect::<Vec<_>>(),
+            [zero]
+        );
+        assert_eq!(
+            a.countdown(Time::HOUR * 2).unwrap().collect::<Vec<_>>(),
+            [a, zero]
+        );
+        assert_eq!(a.countdown(Time::SECOND).unwrap().count(), 5026);
+        assert!(matches!(a.countdown(zero), Err(TimeError::ZeroStep)));
+    }
+    #[rstest]
+    fn test_step_by(fixture: [Time; 2]) {
+        let ticks: Vec<Time> = Time::step_by(Time::ZERO, Time::MINUTE, Time::SECOND * 15)
+            .unwrap()
+            .collect();
+        assert_eq!(
+            ticks,
+            vec![
+                Time::new(0, 0, 0),
+                Time::new(0, 0, 15),
+                Time::new(0, 0, 30),
+                Time::new(0, 0, 45)
+            ]
+        );
+        let day = Time::step_by(Time::ZERO, Time::from_hours(24), Time::HOUR).unwrap();
+        assert_eq!(day.count(), 24);
+        let empty = Time::step_by(fixture[0], fixture[1], Time::SECOND).unwrap();
+        assert_eq!(empty.count(), 0);
+        let max = Time::from_seconds(u64::MAX);
+        let tail = Time::step_by(max - Time::SECOND, max, Time::HOUR).unwrap();
+        assert_eq!(tail.count(), 1);
+        assert!(matches!(
+            Time::step_by(fixture[1], fixture[0], Time::ZERO),
+            Err(TimeError::ZeroStep)
+        ));
+    }
+    #[rstest]
     fn test_add(fixture: [Time; 2]) {
-        let mut a = fixture[0].clone();
-        let b = fixture[1].clone();
-        let c = a.clone() + b.clone();
+        let mut a = fixture[0];
+        let b = fixture[1];
+        let c = a + b;
         assert_eq!(c, a);
-        let d = c.clone() + a.clone();
+        let d = c + a;
         assert_eq!(d.to_string(), "02:47:30");
         a += c;
         assert_eq!(a, d);
     }
     #[rstest]
+    fn test_sum(fixture: [Time; 2]) {
+        let laps = [Time::new(0, 30, 0), Time::new(0, 45, 0)];
+        assert_eq!(laps.iter().sum::<Time>(), Time::new(1, 15, 0));
+        assert_eq!(laps.into_iter().sum::<Time>().to_string(), "01:15:00");
+        assert_eq!(fixture.iter().sum::<Time>(), fixture[0]);
+        assert_eq!(Vec::<Time>::new().into_iter().sum::<Time>(), Time::ZERO);
+    }
+    #[rstest]
+    fn test_assign_ref(fixture: [Time; 2]) {
+        let times = vec![Time::new(0, 30, 0), Time::new(0, 45, 0)];
+        let mut total = fixture[1];
+        for t in &times {
+            total += t;
+        }
+        assert_eq!(total, Time::new(1, 15, 0));
+        for t in &times {
+            total -= t;
+        }
+        assert_eq!(total, fixture[1]);
+        total -= &fixture[0];
+        assert_eq!(total, fixture[1]);
+        assert_eq!(times.len(), 2);
+    }
+    #[rstest]
+    fn test_collect(fixture: [Time; 2]) {
+        let laps = vec![Time::new(0, 0, 10), Time::new(0, 0, 20)];
+        assert_eq!(laps.iter().collect::<Time>(), Time::new(0, 0, 30));
+        assert_eq!(laps.into_iter().collect::<Time>().to_string(), "00:00:30");
+        assert_eq!(fixture.iter().collect::<Time>(), fixture[0]);
+        assert_eq!(Vec::<Time>::new().into_iter().collect::<Time>(), Time::ZERO);
+    }
+    #[rstest]
+    fn test_extend(fixture: [Time; 2]) {
+        let mut total = fixture[0];
+        total.extend(vec![Time::new(0, 0, 10), Time::new(0, 0, 5)]);
+        assert_eq!(total, Time::new(1, 24, 0));
+        total.extend(&[Time::new(0, 36, 0)]);
+        assert_eq!(total.to_string(), "02:00:00");
+        total.extend(Vec::<Time>::new());
+        assert_eq!(total, Time::new(2, 0, 0));
+        let mut zero = fixture[1];
+        zero.extend(fixture.iter());
+        assert_eq!(zero, fixture[0]);
+    }
+    #[rstest]
+    fn test_average(fixture: [Time; 2]) {
+        let laps = [Time::new(0, 0, 10), Time::new(0, 0, 20)];
+        assert_eq!(Time::average(&laps), Some(Time::new(0, 0, 15)));
+        assert_eq!(Time::average(&laps).unwrap().to_string(), "00:00:15");
+        assert_eq!(Time::average(&fixture), Some(Time::new(0, 41, 52)));
+        assert_eq!(Time::average(&fixture[..1]), Some(fixture[0]));
+        assert_eq!(Time::average(&[]), None);
+        assert_eq!(Time::average(&[Time::MAX, Time::MAX]), Some(Time::MAX));
+        assert_eq!(
+            Time::average(&[Time::MAX, Time::ZERO]),
+            Some(Time::from_seconds(u64::MAX / 2))
+        );
+    }
+    #[rstest]
     fn test_mul(fixture: [Time; 2]) {
-        let mut a = fixture[0].clone();
-        let b = a.clone() * 2;
+        let mut a = fixture[0];
+        let b = a * 2;
         assert_eq!(b.to_string(), "02:47:30");
         let c = b * 3;
         assert_eq!(c.to_string(), "08:22:30");
@@ -240,29 +2102,183 @@ mod tests {
         assert_eq!(a.to_string(), "02:47:30");
     }
     #[rstest]
+    fn test_mul_commutative(fixture: [Time; 2]) {
+        assert_eq!(2 * Time::new(0, 30, 0), Time::new(0, 30, 0) * 2);
+        assert_eq!((3 * fixture[0]).to_string(), "04:11:15".to_string());
+    }
+    #[rstest]
+    fn test_mul_f64(fixture: [Time; 2]) {
+        let a = Time::new(1, 0, 0);
+        assert_eq!(a * 1.5, Time::new(1, 30, 0));
+        assert_eq!(1.5 * a, a * 1.5);
+        assert_eq!(fixture[0] * 0.5, Time::new(0, 41, 53));
+        assert_eq!(fixture[0] * 1.0, fixture[0]);
+        assert_eq!(a * -2.0, fixture[1]);
+        assert_eq!(a * f64::NAN, fixture[1]);
+        assert_eq!((a * f64::INFINITY).total_seconds(), u64::MAX);
+    }
+    #[rstest]
+    fn test_mul_large() {
+        let a = Time::new(1000, 0, 0) * 1_000_000;
+        assert_eq!(a.total_seconds(), 3_600_000_000_000);
+        assert_eq!(a.day(), 41_666_666);
+        assert_eq!(a.hour(), 16);
+        assert_eq!(a.to_string(), "41666666 days 16:00:00".to_string());
+    }
+    #[rstest]
+    fn test_checked_add_mul(fixture: [Time; 2]) {
+        let a = fixture[0];
+        assert_eq!(a.checked_add(a), Some(a + a));
+        assert_eq!(a.checked_mul(3), Some(a * 3));
+        assert_eq!(fixture[1].checked_mul(usize::MAX), Some(fixture[1]));
+        let big = Time::new(u64::MAX / 3600, 0, 0);
+        assert_eq!(big.checked_mul(10), None);
+        assert_eq!(big.checked_add(big), None);
+        assert_eq!(big.checked_add(fixture[1]), Some(big));
+    }
+    #[rstest]
+    fn test_wrapping(fixture: [Time; 2]) {
+        let [a, zero] = fixture;
+        let max = Time::from_seconds(u64::MAX);
+        assert_eq!(a.wrapping_add(a), a + a);
+        assert_eq!(max.wrapping_add(Time::SECOND), zero);
+        assert_eq!(max.wrapping_add(a), Time::from_seconds(5024));
+        assert_eq!(a.wrapping_mul(3), a * 3);
+        assert_eq!(max.wrapping_mul(2), Time::from_seconds(u64::MAX - 1));
+        assert_eq!(Time::from_seconds(1 << 63).wrapping_mul(2), zero);
+        assert_eq!(a.wrapping_mul(0), zero);
+    }
+    #[rstest]
+    fn test_saturating(fixture: [Time; 2]) {
+        let a = fixture[0];
+        let max = Time::new(0, 0, u64::MAX);
+        assert_eq!(a.saturating_add(a), a + a);
+        assert_eq!(max.saturating_add(a), max);
+        assert_eq!(max.saturating_add(a).total_seconds(), u64::MAX);
+        assert_eq!(a.saturating_sub(Time::new(4, 0, 0)), fixture[1]);
+        assert_eq!(Time::new(4, 0, 0).saturating_sub(a), Time::new(4, 0, 0) - a);
+        assert_eq!(
+            Time::new(1, 0, 0).saturating_sub(Time::new(2, 0, 0)),
+            Time::new(0, 0, 0)
+        );
+    }
+    #[rstest]
+    fn test_mul_assign_unnormalized(fixture: [Time; 2]) {
+        let mut a = fixture[1];
+        a.second = 5025;
+        a.minute = 75;
+        let b = a * 2;
+        a *= 2;
+        assert_eq!(a.to_string(), b.to_string());
+        assert_eq!(a.to_string(), "05:17:30".to_string());
+    }
+    #[rstest]
     fn test_sub(fixture: [Time; 2]) {
-        let mut a = fixture[0].clone();
-        let mut b = fixture[1].clone();
+        let mut a = fixture[0];
+        let mut b = fixture[1];
         b.hour = 4;
-        let c = b.clone() - a.clone();
+        let c = b - a;
         assert_eq!(c.to_string(), "02:36:15".to_string());
-        let d = c.clone() - a.clone();
+        let d = c - a;
         assert_eq!(d.to_string(), "01:12:30".to_string());
-        let e = a.clone() - c.clone();
-        assert_eq!(d, e);
-        b -= a.clone();
+        let e = a - c;
+        assert_ne!(d, e);
+        assert_eq!(e, fixture[1]);
+        b -= a;
         assert_eq!(b.to_string(), "02:36:15".to_string());
-        a -= b.clone();
-        assert_eq!(a.to_string(), "01:12:30".to_string());
+        a -= b;
+        assert_eq!(a.to_string(), "00:00:00".to_string());
+    }
+    #[rstest]
+    fn test_abs_diff(fixture: [Time; 2]) {
+        let a = Time::new(1, 0, 0);
+        let b = Time::new(0, 20, 0);
+        assert_eq!(a.abs_diff(b), Time::new(0, 40, 0));
+        assert_eq!(b.abs_diff(a), a.abs_diff(b));
+        assert_eq!(fixture[0].abs_diff(fixture[0]), fixture[1]);
+        assert_eq!(fixture[1].abs_diff(fixture[0]), fixture[0]);
+    }
+    #[rstest]
+    fn test_split_even(fixture: [Time; 2]) {
+        let parts = Time::from_seconds(100).split_even(3).unwrap();
+        assert_eq!(
+            parts,
+            vec![
+                Time::from_seconds(34),
+                Time::from_seconds(33),
+                Time::from_seconds(33)
+            ]
+        );
+        for (t, n) in [
+            (fixture[0], 1),
+            (fixture[0], 7),
+            (fixture[0], 5025),
+            (fixture[0], 6000),
+            (fixture[1], 4),
+            (Time::new(50, 0, 1), 24),
+        ] {
+            let parts = t.split_even(n).unwrap();
+            assert_eq!(parts.len(), n);
+            assert_eq!(parts.iter().sum::<Time>(), t);
+            assert!(parts.windows(2).all(|w| w[0] >= w[1]));
+            assert!(parts[0] - parts[n - 1] <= Time::SECOND);
+        }
+        assert_eq!(fixture[0].split_even(0), Err(TimeError::DivisionByZero));
+    }
+    #[rstest]
+    fn test_duration_between(fixture: [Time; 2]) {
+        let [a, zero] = fixture;
+        let (start, end) = (Time::new(0, 20, 0), Time::new(1, 0, 0));
+        assert_eq!(Time::duration_between(start, end), Time::new(0, 40, 0));
+        assert_eq!(
+            Time::duration_between(start, end),
+            Time::duration_between(end, start)
+        );
+        assert_eq!(Time::duration_between(a, zero), a);
+        assert_eq!(Time::duration_between(zero, a), a);
+        assert_eq!(Time::duration_between(a, a), zero);
+    }
+    #[rstest]
+    fn test_approx_eq(fixture: [Time; 2]) {
+        let a = Time::new(0, 0, 10);
+        let b = Time::new(0, 0, 12);
+        assert!(a.approx_eq(&b, Time::new(0, 0, 5)));
+        assert!(b.approx_eq(&a, Time::new(0, 0, 5)));
+        assert!(!a.approx_eq(&b, Time::new(0, 0, 1)));
+        assert!(a.approx_eq(&b, Time::new(0, 0, 2)));
+        assert!(fixture[0].approx_eq(&fixture[0], Time::ZERO));
+        assert!(!fixture[0].approx_eq(&fixture[1], Time::ZERO));
+    }
+    #[rstest]
+    fn test_checked_sub(fixture: [Time; 2]) {
+        let a = fixture[0];
+        let b = Time::new(4, 0, 0);
+        assert_eq!(a.checked_sub(b), None);
+        assert_eq!(b.checked_sub(a), Some(b - a));
+        assert_eq!(a.checked_sub(a), Some(fixture[1]));
+        assert_eq!(Time::new(0, 20, 0).checked_sub(Time::new(1, 0, 0)), None);
+        assert_eq!(
+            Time::new(1, 0, 0).checked_sub(Time::new(0, 20, 0)),
+            Some(Time::new(0, 40, 0))
+        );
+    }
+    #[rstest]
+    fn test_checked_vs_saturating_sub(fixture: [Time; 2]) {
+        let [a, zero] = fixture;
+        let b = Time::new(4, 0, 0);
+        assert_eq!(a.saturating_sub(b), zero);
+        assert_eq!(a.checked_sub(b), None);
+        assert_eq!(b.checked_sub(a), Some(b.saturating_sub(a)));
+        assert_eq!(zero.checked_sub(zero), Some(zero.saturating_sub(zero)));
     }
     #[rstest]
     fn test_div(fixture: [Time; 2]) {
-        let mut a = fixture[0].clone();
-        let mut b = fixture[1].clone();
+        let mut a = fixture[0];
+        let mut b = fixture[1];
         b.minute = 20;
-        let c = a.clone() / b;
+        let c = a / b;
         assert_eq!(c, 4.1875);
-        let d = a.clone() / 3;
+        let d = a / 3;
         assert_eq!(d.to_string(), "00:27:55".to_string());
         a /= 4;
         assert_eq!(a.to_string(), "00:20:56".to_string());
@@ -270,8 +2286,245 @@ mod tests {
         assert_eq!(a.to_string(), "00:10:28".to_string());
     }
     #[rstest]
+    fn test_checked_ratio(fixture: [Time; 2]) {
+        let [a, zero] = fixture;
+        assert_eq!(Time::new(1, 0, 0).checked_ratio(&Time::ZERO), None);
+        assert_eq!(zero.checked_ratio(&zero), None);
+        assert_eq!(a.checked_ratio(&Time::new(0, 20, 0)), Some(4.1875));
+        assert_eq!(zero.checked_ratio(&a), Some(0.0));
+        assert_eq!(a.checked_ratio(&a), Some(a / a));
+        assert!((a / zero).is_infinite());
+    }
+    #[rstest]
+    fn test_div_ratio_precision() {
+        let a = Time::from_seconds((1 << 24) + 1);
+        assert_eq!(a / Time::SECOND, 16_777_217.0);
+        assert_ne!(16_777_217.0_f32 as f64, 16_777_217.0);
+        assert_eq!(Time::new(3, 0, 0) / Time::new(2, 0, 0), 1.5);
+    }
+    #[rstest]
+    fn test_rem(fixture: [Time; 2]) {
+        let mut a = fixture[0];
+        assert_eq!(Time::new(0, 0, 100) % 30, Time::new(0, 0, 10));
+        assert_eq!((a % 4).to_string(), "00:00:01".to_string());
+        assert_eq!(a % 5025, fixture[1]);
+        assert_eq!(a.checked_rem(0), Err(TimeError::DivisionByZero));
+        a %= 60;
+        assert_eq!(a.to_string(), "00:00:45".to_string());
+    }
+    #[rstest]
+    #[should_panic]
+    fn test_rem_panic(fixture: [Time; 2]) {
+        let _ = fixture[0] % 0;
+    }
+    #[rstest]
+    fn test_div_rem(fixture: [Time; 2]) {
+        let a = Time::new(0, 0, 100);
+        let (q, r) = a.div_rem(30);
+        assert_eq!(q.to_string(), "00:00:03".to_string());
+        assert_eq!(r.to_string(), "00:00:10".to_string());
+        assert_eq!(q * 30 + r, a);
+        let (q, r) = fixture[0].div_rem(7);
+        assert_eq!((q, r), (fixture[0] / 7, fixture[0] % 7));
+        assert_eq!(q * 7 + r, fixture[0]);
+    }
+    #[rstest]
+    #[should_panic]
+    fn test_div_rem_panic(fixture: [Time; 2]) {
+        let _ = fixture[0].div_rem(0);
+    }
+    #[rstest]
+    fn test_round_to(fixture: [Time; 2]) {
+        let quarter = Time::new(0, 15, 0);
+        assert_eq!(Time::new(0, 7, 30).round_to(quarter), Time::new(0, 15, 0));
+        assert_eq!(Time::new(0, 7, 29).round_to(quarter), fixture[1]);
+        assert_eq!(fixture[0].round_to(quarter), Time::new(1, 30, 0));
+        assert_eq!(fixture[0].round_to(Time::SECOND * 5), Time::new(1, 23, 45));
+        assert_eq!(fixture[0].floor_to(quarter), Time::new(1, 15, 0));
+        assert_eq!(fixture[0].ceil_to(quarter), Time::new(1, 30, 0));
+        assert_eq!(Time::HOUR.floor_to(quarter), Time::HOUR);
+        assert_eq!(Time::HOUR.ceil_to(quarter), Time::HOUR);
+        assert_eq!(fixture[1].ceil_to(quarter), fixture[1]);
+        assert_eq!(Time::MAX.ceil_to(quarter), Time::MAX);
+        assert_eq!(Time::MAX.round_to(Time::from_seconds(20)), Time::MAX);
+        assert_eq!(Time::MAX.round_to(quarter), Time::MAX.floor_to(quarter));
+        assert_eq!(Time::MAX.ceil_to(Time::MAX), Time::MAX);
+    }
+    #[rstest]
+    #[should_panic]
+    fn test_round_to_panic(fixture: [Time; 2]) {
+        let _ = fixture[0].round_to(fixture[1]);
+    }
+    #[rstest]
+    fn test_midpoint(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        assert_eq!(b.midpoint(Time::new(0, 0, 10)), Time::new(0, 0, 5));
+        assert_eq!(a.midpoint(b), b.midpoint(a));
+        assert_eq!(a.midpoint(b).to_string(), "00:41:52".to_string());
+        assert_eq!(a.midpoint(a), a);
+        let max = Time::from_seconds(u64::MAX);
+        assert_eq!(max.midpoint(max), max);
+        assert_eq!(max.midpoint(b).total_seconds(), u64::MAX / 2);
+    }
+    #[rstest]
+    fn test_lerp(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        let end = Time::new(0, 0, 100);
+        assert_eq!(Time::lerp(b, end, 0.25), Time::new(0, 0, 25));
+        assert_eq!(Time::lerp(end, b, 0.25), Time::new(0, 0, 75));
+        assert_eq!(Time::lerp(b, a, 0.0), b);
+        assert_eq!(Time::lerp(b, a, 1.0), a);
+        assert_eq!(Time::lerp(b, a, 0.5), Time::new(0, 41, 53));
+        assert_eq!(Time::lerp(b, a, -1.0), b);
+        assert_eq!(Time::lerp(b, a, 2.0), a);
+        assert_eq!(Time::lerp(b, a, f64::NAN), b);
+        let max = Time::from_seconds(u64::MAX);
+        assert_eq!(Time::lerp(b, max, 1.0), max);
+        assert_eq!(Time::lerp(Time::SECOND, max, 1.0), max);
+    }
+    #[rstest]
+    fn test_scale_ratio(fixture: [Time; 2]) {
+        let [a, zero] = fixture;
+        assert_eq!(Time::new(1, 0, 0).scale_ratio(3, 4), Time::new(0, 45, 0));
+        assert_eq!(a.scale_ratio(1, 1), a);
+        assert_eq!(a.scale_ratio(2, 1), a * 2);
+        assert_eq!(a.scale_ratio(1, 2), Time::from_seconds(2512));
+        assert_eq!(zero.scale_ratio(5, 7), zero);
+        assert_eq!(a.scale_ratio(0, 3), zero);
+        // `MAX * 3` only fits in the `u128` intermediate.
+        assert_eq!(
+            Time::MAX.scale_ratio(3, 4),
+            Time::from_seconds(u64::MAX / 4 * 3 + 2)
+        );
+        assert_eq!(Time::MAX.scale_ratio(usize::MAX, usize::MAX), Time::MAX);
+    }
+    #[rstest]
+    #[should_panic]
+    fn test_scale_ratio_zero_den(fixture: [Time; 2]) {
+        let _ = fixture[0].scale_ratio(1, 0);
+    }
+    #[rstest]
+    #[should_panic]
+    fn test_scale_ratio_overflow() {
+        let _ = Time::MAX.scale_ratio(2, 1);
+    }
+    #[rstest]
+    fn test_checked_div(fixture: [Time; 2]) {
+        let mut a = fixture[0];
+        assert!(Time::new(1, 0, 0).checked_div(0).is_err());
+        assert_eq!(a.checked_div(0), Err(TimeError::DivisionByZero));
+        assert_eq!(a.checked_div(3), Ok(a / 3));
+        assert_eq!(a.checked_div_assign(0), Err(TimeError::DivisionByZero));
+        assert_eq!(a, fixture[0]);
+        assert_eq!(a.checked_div_assign(4), Ok(()));
+        assert_eq!(a.to_string(), "00:20:56".to_string());
+    }
+    #[rstest]
+    fn test_checked_mul_assign(fixture: [Time; 2]) {
+        let mut a = fixture[0];
+        assert_eq!(a.checked_mul_assign(2), Ok(()));
+        assert_eq!(a.to_string(), "02:47:30".to_string());
+        let mut big = Time::new(u64::MAX / 3600, 0, 0);
+        let before = big;
+        assert_eq!(big.checked_mul_assign(10), Err(TimeError::Overflow));
+        assert_eq!(big, before);
+        assert_eq!(big.to_string(), before.to_string());
+        let mut zero = fixture[1];
+        assert_eq!(zero.checked_mul_assign(usize::MAX), Ok(()));
+        assert_eq!(zero, fixture[1]);
+    }
+    #[rstest]
+    fn test_error_display() {
+        assert_eq!(
+            TimeError::DivisionByZero.to_string(),
+            "cannot divide `Time` by zero"
+        );
+        let err: TimeError = "".parse::<Time>().unwrap_err().into();
+        assert_eq!(err, TimeError::Parse(ParseTimeError::Empty));
+        assert_eq!(err.to_string(), ParseTimeError::Empty.to_string());
+    }
+    #[rstest]
+    fn test_ref_ops(fixture: [Time; 2]) {
+        let a = &fixture[0];
+        let b = &Time::new(4, 0, 0);
+        assert_eq!((a + b).to_string(), "05:23:45".to_string());
+        assert_eq!((b - a).to_string(), "02:36:15".to_string());
+        assert_eq!((a * 2).to_string(), "02:47:30".to_string());
+        assert_eq!((a / 3).to_string(), "00:27:55".to_string());
+        assert_eq!(a, &fixture[0]);
+    }
+    #[rstest]
     #[should_panic]
     fn test_panic(fixture: [Time; 2]) {
-        let _ = fixture[0].clone() / 0;
+        let _ = fixture[0] / 0;
+    }
+
+    // Raw, possibly unnormalized fields, small enough that sums and
+    // products in the properties below can't overflow.
+    fn any_time() -> impl Strategy<Value = Time> {
+        (0..1_000u64, 0..100_000u64, 0..100_000u64, 0..10_000_000u64).prop_map(
+            |(day, hour, minute, second)| Time {
+                day,
+                hour,
+                minute,
+                second,
+            },
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn prop_add_sub_round_trip(a in any_time(), b in any_time()) {
+            prop_assert_eq!((a + b) - b, a);
+            let mut c = a;
+            c += b;
+            c -= b;
+            prop_assert_eq!(c, a);
+        }
+
+        #[test]
+        fn prop_sub_saturates(a in any_time(), b in any_time()) {
+            let diff = a - b;
+            if b >= a {
+                prop_assert!(diff.is_zero());
+            } else {
+                prop_assert_eq!(diff + b, a);
+            }
+            prop_assert_eq!(a.signed_sub(b) + b, SignedTime::from_secs(a.total_seconds() as i64));
+        }
+
+        #[test]
+        fn prop_mul_div_round_trip(a in any_time(), n in 1..1_000usize) {
+            prop_assert_eq!(a * n / n, a);
+            let mut c = a;
+            c *= n;
+            c /= n;
+            prop_assert_eq!(c, a);
+            prop_assert_eq!((a * n) % n, Time::ZERO);
+        }
+
+        #[test]
+        fn prop_normalize_idempotent(a in any_time()) {
+            let once = a.normalized();
+            let twice = once.normalized();
+            prop_assert!(once.is_normalized());
+            prop_assert_eq!(
+                (once.day, once.hour, once.minute, once.second),
+                (twice.day, twice.hour, twice.minute, twice.second)
+            );
+            prop_assert_eq!(once.total_seconds(), a.total_seconds());
+        }
+
+        #[test]
+        fn prop_seconds_round_trip(seconds in any::<u64>()) {
+            let t = Time::from_seconds(seconds);
+            prop_assert_eq!(t.total_seconds(), seconds);
+            prop_assert!(t.is_normalized());
+        }
+
+        #[test]
+        fn prop_str_round_trip(a in any_time()) {
+            prop_assert_eq!(a.normalized().to_string().parse::<Time>(), Ok(a));
+        }
     }
 }
@@ -0,0 +1,404 @@
+use alloc::format;
+use alloc::string::ToString;
+use core::cmp::Ordering;
+use core::fmt::{self, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+use core::str::FromStr;
+use core::time::Duration;
+
+use crate::{ParseTimeError, Time, TimeError};
+
+/// A [`Time`] with millisecond precision.
+///
+/// `Time` itself counts whole seconds; `TimeMs` carries the sub-second part
+/// alongside it and renders as `HH:MM:SS.mmm`.
+///
+/// ```
+/// use time_calculator::TimeMs;
+///
+/// assert_eq!(TimeMs::from_millis(5025123).to_string(), "01:23:45.123");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TimeMs {
+    time: Time,
+    millisecond: u64,
+}
+
+impl TimeMs {
+    /// Creates a new `TimeMs`, carrying overflowing components upwards.
+    pub fn new(hour: u64, minute: u64, second: u64, millisecond: u64) -> Self {
+        let mut output = TimeMs {
+            time: Time {
+                day: 0,
+                hour,
+                minute,
+                second,
+            },
+            millisecond,
+        };
+        output.normalize();
+        output
+    }
+
+    /// Creates a `TimeMs` from a number of milliseconds.
+    pub fn from_millis(millis: u64) -> Self {
+        TimeMs::new(0, 0, 0, millis)
+    }
+
+    /// Returns the whole-second part, dropping the milliseconds.
+    pub fn time(&self) -> Time {
+        self.time
+    }
+
+    /// Returns the millisecond component, always below 1000.
+    pub fn millisecond(&self) -> u64 {
+        self.millisecond
+    }
+
+    /// Returns the whole span expressed in seconds, truncating the milliseconds.
+    pub fn total_seconds(&self) -> u64 {
+        self.time.total_seconds()
+    }
+
+    /// Returns the whole span expressed in milliseconds.
+    ///
+    /// This is a `u128`, like [`core::time::Duration::as_millis`], so it can't
+    /// overflow even for the largest `Time`.
+    pub fn total_millis(&self) -> u128 {
+        self.time.total_seconds() as u128 * 1000 + self.millisecond as u128
+    }
+
+    /// Parses `HH:MM:SS` with an optional fraction of one to three digits,
+    /// so `01:23:45.5` is 500 milliseconds.
+    ///
+    /// This is more lenient than `FromStr`, which requires exactly three
+    /// fraction digits. More than three digits are rejected rather than
+    /// rounded.
+    ///
+    /// ```
+    /// use time_calculator::TimeMs;
+    ///
+    /// assert_eq!(TimeMs::parse_hhmmss_millis("01:23:45.5"), Ok(TimeMs::new(1, 23, 45, 500)));
+    /// assert_eq!(TimeMs::parse_hhmmss_millis("01:23:45"), Ok(TimeMs::new(1, 23, 45, 0)));
+    /// assert!(TimeMs::parse_hhmmss_millis("01:23:45.6789").is_err());
+    /// ```
+    pub fn parse_hhmmss_millis(s: &str) -> Result<TimeMs, TimeError> {
+        let s = s.trim_ascii();
+        let (time, fraction) = match s.split_once('.') {
+            Some((time, fraction)) => (time, fraction),
+            None => (s, "000"),
+        };
+        if !(1..=3).contains(&fraction.len()) || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseTimeError::InvalidField(fraction.to_string()).into());
+        }
+        let digits: u64 = fraction
+            .parse()
+            .map_err(|_| ParseTimeError::InvalidField(fraction.to_string()))?;
+        let mut output = TimeMs {
+            time: time.parse()?,
+            millisecond: digits * 10u64.pow(3 - fraction.len() as u32),
+        };
+        output.normalize();
+        Ok(output)
+    }
+
+    fn from_total_millis(millis: u128) -> Self {
+        TimeMs {
+            time: Time::new(0, 0, (millis / 1000) as u64),
+            millisecond: (millis % 1000) as u64,
+        }
+    }
+
+    fn normalize(&mut self) {
+        self.time.second += self.millisecond / 1000;
+        self.millisecond %= 1000;
+        self.time.normalize();
+    }
+}
+
+impl From<Time> for TimeMs {
+    fn from(time: Time) -> Self {
+        TimeMs {
+            time,
+            millisecond: 0,
+        }
+    }
+}
+
+impl From<TimeMs> for Time {
+    /// Truncates to whole seconds.
+    fn from(time: TimeMs) -> Self {
+        time.time
+    }
+}
+
+/// Truncates anything finer than a millisecond.
+impl From<Duration> for TimeMs {
+    fn from(duration: Duration) -> Self {
+        TimeMs::new(0, 0, duration.as_secs(), duration.subsec_millis() as u64)
+    }
+}
+
+impl From<TimeMs> for Duration {
+    fn from(time: TimeMs) -> Self {
+        Duration::from_secs(time.total_seconds()) + Duration::from_millis(time.millisecond)
+    }
+}
+
+impl PartialEq for TimeMs {
+    fn eq(&self, other: &Self) -> bool {
+        self.total_millis() == other.total_millis()
+    }
+}
+
+impl Eq for TimeMs {}
+
+impl Hash for TimeMs {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.total_millis().hash(state);
+    }
+}
+
+impl PartialOrd for TimeMs {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TimeMs {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total_millis().cmp(&other.total_millis())
+    }
+}
+
+/// Renders as `HH:MM:SS.mmm`, honouring the same alternate form, width, fill
+/// and alignment as [`Time`]'s `Display`.
+impl Display for TimeMs {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let time = if f.alternate() {
+            format!("{:#}", self.time)
+        } else {
+            self.time.to_string()
+        };
+        f.pad(&format!("{}.{:03}", time, self.millisecond))
+    }
+}
+
+impl FromStr for TimeMs {
+    type Err = ParseTimeError;
+
+    /// Parses the `HH:MM:SS.mmm` form produced by `Display`, ignoring
+    /// surrounding ASCII whitespace like [`Time`]'s parser does.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim_ascii();
+        if s.is_empty() {
+            return Err(ParseTimeError::Empty);
+        }
+        let (time, fraction) = s
+            .rsplit_once('.')
+            .ok_or_else(|| ParseTimeError::InvalidField(s.to_string()))?;
+        if fraction.len() != 3 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseTimeError::InvalidField(fraction.to_string()));
+        }
+        let mut output = TimeMs {
+            time: time.parse()?,
+            millisecond: fraction
+                .parse()
+                .map_err(|_| ParseTimeError::InvalidField(fraction.to_string()))?,
+        };
+        output.normalize();
+        Ok(output)
+    }
+}
+
+impl Add for TimeMs {
+    type Output = Self;
+    fn add(self, other: Self) -> TimeMs {
+        let mut output = TimeMs {
+            time: self.time + other.time,
+            millisecond: self.millisecond + other.millisecond,
+        };
+        output.normalize();
+        output
+    }
+}
+
+impl AddAssign for TimeMs {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+/// Subtraction saturates at zero, like it does for [`Time`].
+impl Sub for TimeMs {
+    type Output = Self;
+    fn sub(self, other: Self) -> TimeMs {
+        TimeMs::from_total_millis(self.total_millis().saturating_sub(other.total_millis()))
+    }
+}
+
+impl SubAssign for TimeMs {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<usize> for TimeMs {
+    type Output = Self;
+    fn mul(self, int: usize) -> TimeMs {
+        let mut output = TimeMs {
+            time: self.time * int,
+            millisecond: self.millisecond * int as u64,
+        };
+        output.normalize();
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ParseTimeError, Time, TimeError, TimeMs};
+    use rstest::*;
+    use std::time::Duration;
+    #[fixture]
+    pub fn fixture() -> [TimeMs; 2] {
+        [TimeMs::new(1, 23, 45, 123), TimeMs::new(0, 0, 0, 0)]
+    }
+    #[rstest]
+    fn test_from_millis(fixture: [TimeMs; 2]) {
+        let a = TimeMs::from_millis(5025123);
+        assert_eq!(a, fixture[0]);
+        assert_eq!(a.time(), Time::new(1, 23, 45));
+        assert_eq!(a.millisecond(), 123);
+        assert_eq!(a.total_seconds(), 5025);
+        assert_eq!(a.total_millis(), 5025123);
+        assert_eq!(TimeMs::from_millis(0), fixture[1]);
+    }
+    #[rstest]
+    fn test_str(fixture: [TimeMs; 2]) {
+        assert_eq!(fixture[0].to_string(), "01:23:45.123".to_string());
+        assert_eq!(fixture[1].to_string(), "00:00:00.000".to_string());
+        assert_eq!(
+            TimeMs::new(0, 0, 1, 5).to_string(),
+            "00:00:01.005".to_string()
+        );
+        assert_eq!(format!("{:>14}", fixture[0]), "  01:23:45.123");
+        assert_eq!(format!("{:*<13}", fixture[1]), "00:00:00.000*");
+        assert_eq!(format!("{:#}", fixture[0]), "1:23:45.123");
+    }
+    #[rstest]
+    fn test_normalize() {
+        let a = TimeMs::new(0, 59, 59, 1500);
+        assert_eq!(a.to_string(), "01:00:00.500".to_string());
+        assert_eq!(a, TimeMs::from_millis(3_600_500));
+    }
+    #[rstest]
+    fn test_from_str(fixture: [TimeMs; 2]) {
+        let a: TimeMs = "01:23:45.123".parse().unwrap();
+        assert_eq!(a, fixture[0]);
+        let b: TimeMs = fixture[1].to_string().parse().unwrap();
+        assert_eq!(b, fixture[1]);
+        assert_eq!(" 01:23:45.123\n".parse::<TimeMs>(), Ok(fixture[0]));
+        assert_eq!("".parse::<TimeMs>(), Err(ParseTimeError::Empty));
+        assert_eq!(
+            "01:23:45".parse::<TimeMs>(),
+            Err(ParseTimeError::InvalidField("01:23:45".to_string()))
+        );
+        assert_eq!(
+            "01:23:45.12".parse::<TimeMs>(),
+            Err(ParseTimeError::InvalidField("12".to_string()))
+        );
+        assert_eq!(
+            "01:23.123".parse::<TimeMs>(),
+            Err(ParseTimeError::FieldCount(2))
+        );
+        assert_eq!(
+            "5124095576030432:00:00.000".parse::<TimeMs>(),
+            Err(ParseTimeError::Overflow)
+        );
+    }
+    #[rstest]
+    fn test_parse_hhmmss_millis(fixture: [TimeMs; 2]) {
+        let [a, b] = fixture;
+        assert_eq!(
+            TimeMs::parse_hhmmss_millis("01:23:45.5"),
+            Ok(TimeMs::new(1, 23, 45, 500))
+        );
+        assert_eq!(
+            TimeMs::parse_hhmmss_millis("01:23:45.678"),
+            Ok(TimeMs::new(1, 23, 45, 678))
+        );
+        assert_eq!(
+            TimeMs::parse_hhmmss_millis("01:23:45.12"),
+            Ok(TimeMs::new(1, 23, 45, 120))
+        );
+        assert_eq!(TimeMs::parse_hhmmss_millis("01:23:45.123"), Ok(a));
+        assert_eq!(
+            TimeMs::parse_hhmmss_millis("01:23:45"),
+            Ok(TimeMs::from(Time::new(1, 23, 45)))
+        );
+        assert_eq!(TimeMs::parse_hhmmss_millis("00:00:00.0\n"), Ok(b));
+        assert_eq!(TimeMs::parse_hhmmss_millis(&a.to_string()), Ok(a));
+    }
+    #[rstest]
+    fn test_parse_hhmmss_millis_error() {
+        for (s, field) in [
+            ("01:23:45.6789", "6789"),
+            ("01:23:45.", ""),
+            ("01:23:45.5x", "5x"),
+            ("01:23:45.-5", "-5"),
+        ] {
+            assert_eq!(
+                TimeMs::parse_hhmmss_millis(s),
+                Err(TimeError::Parse(ParseTimeError::InvalidField(
+                    field.to_string()
+                )))
+            );
+        }
+        assert_eq!(
+            TimeMs::parse_hhmmss_millis("01:23.5"),
+            Err(TimeError::Parse(ParseTimeError::FieldCount(2)))
+        );
+        assert_eq!(
+            TimeMs::parse_hhmmss_millis(""),
+            Err(TimeError::Parse(ParseTimeError::Empty))
+        );
+        assert_eq!(
+            TimeMs::parse_hhmmss_millis("5124095576030432:00:00"),
+            Err(TimeError::Overflow)
+        );
+    }
+    #[rstest]
+    fn test_conversions(fixture: [TimeMs; 2]) {
+        assert_eq!(Time::from(fixture[0]), Time::new(1, 23, 45));
+        assert_eq!(
+            TimeMs::from(Time::new(1, 23, 45)),
+            TimeMs::from_millis(5025000)
+        );
+    }
+    #[rstest]
+    fn test_duration(fixture: [TimeMs; 2]) {
+        let d = Duration::from_millis(5025123);
+        assert_eq!(TimeMs::from(d), fixture[0]);
+        assert_eq!(Duration::from(fixture[0]), d);
+        assert_eq!(
+            TimeMs::from(Duration::from_nanos(1_999_999)),
+            TimeMs::from_millis(1)
+        );
+    }
+    #[rstest]
+    fn test_arithmetic(fixture: [TimeMs; 2]) {
+        let mut a = fixture[0];
+        let b = TimeMs::new(0, 0, 0, 900);
+        assert_eq!((a + b).to_string(), "01:23:46.023".to_string());
+        assert_eq!((a - b).to_string(), "01:23:44.223".to_string());
+        assert_eq!(b - a, fixture[1]);
+        assert_eq!((a * 2).to_string(), "02:47:30.246".to_string());
+        a += b;
+        assert_eq!(a, fixture[0] + b);
+        a -= b;
+        assert_eq!(a, fixture[0]);
+        assert!(b < a);
+    }
+}
@@ -0,0 +1,455 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+use crate::{parse_separated, round_to_u64, ParseTimeError, Time, TimeError};
+
+impl Time {
+    /// Parses `SS`, `MM:SS` or `HH:MM:SS`, normalizing the result.
+    ///
+    /// A single field is a number of seconds, two fields are minutes and
+    /// seconds, and three are hours, minutes and seconds. Surrounding ASCII
+    /// whitespace is ignored.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::parse_flexible("90"), Ok(Time::new(0, 1, 30)));
+    /// assert_eq!(Time::parse_flexible("5:00"), Ok(Time::new(0, 5, 0)));
+    /// assert_eq!(Time::parse_flexible("1:23:45"), Ok(Time::new(1, 23, 45)));
+    /// ```
+    pub fn parse_flexible(s: &str) -> Result<Time, TimeError> {
+        let s = s.trim_ascii();
+        if s.is_empty() {
+            return Err(ParseTimeError::Empty.into());
+        }
+        let fields = s
+            .split(':')
+            .map(|field| {
+                u64::from_str(field).map_err(|_| ParseTimeError::InvalidField(field.to_string()))
+            })
+            .collect::<Result<Vec<u64>, ParseTimeError>>()?;
+        let output = match fields[..] {
+            [second] => Time::checked_from_parts(0, 0, 0, second),
+            [minute, second] => Time::checked_from_parts(0, 0, minute, second),
+            [hour, minute, second] => Time::checked_from_parts(0, hour, minute, second),
+            _ => return Err(ParseTimeError::FieldCount(fields.len()).into()),
+        };
+        output.ok_or(TimeError::Overflow)
+    }
+
+    /// Parses the `Display` form with `sep` in place of `:`, such as
+    /// `01.23.45` for `.`.
+    ///
+    /// `sep` should not be a digit or a space, since those are part of the
+    /// rest of the format.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::parse_with_sep("01-23-45", '-'), Ok(Time::new(1, 23, 45)));
+    /// assert!(Time::parse_with_sep("01:23:45", '-').is_err());
+    /// ```
+    pub fn parse_with_sep(s: &str, sep: char) -> Result<Time, TimeError> {
+        Ok(parse_separated(s, sep)?)
+    }
+
+    /// Parses a decimal number followed by a unit suffix: `h` for hours, `m`
+    /// for minutes or `s` for seconds.
+    ///
+    /// The value is rounded to the nearest second. Surrounding ASCII whitespace
+    /// is ignored, but signs, exponents and inner whitespace aren't accepted.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::parse_unit("1.5h"), Ok(Time::new(1, 30, 0)));
+    /// assert_eq!(Time::parse_unit("90m"), Ok(Time::new(1, 30, 0)));
+    /// assert_eq!(Time::parse_unit("45s"), Ok(Time::new(0, 0, 45)));
+    /// assert!(Time::parse_unit("1.5d").is_err());
+    /// ```
+    pub fn parse_unit(s: &str) -> Result<Time, TimeError> {
+        let s = s.trim_ascii();
+        if s.is_empty() {
+            return Err(ParseTimeError::Empty.into());
+        }
+        let invalid = || TimeError::from(ParseTimeError::InvalidField(s.to_string()));
+        let (number, scale) = match s.as_bytes()[s.len() - 1] {
+            b'h' => (&s[..s.len() - 1], 3600.0),
+            b'm' => (&s[..s.len() - 1], 60.0),
+            b's' => (&s[..s.len() - 1], 1.0),
+            _ => return Err(invalid()),
+        };
+        let seconds = parse_decimal(number).ok_or_else(invalid)? * scale;
+        if seconds >= u64::MAX as f64 {
+            return Err(TimeError::Overflow);
+        }
+        Ok(Time::from_seconds(round_to_u64(seconds)))
+    }
+
+    /// Parses concatenated unit groups such as `1h23m45s`, `2h30m` or `45s`.
+    ///
+    /// Each group is a whole number followed by `h`, `m` or `s`. Groups may be
+    /// left out but must appear in that order, and each unit at most once, so
+    /// `1h1h` and `5m1h` are rejected. Whitespace between groups is ignored.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::parse_compound("1h23m45s"), Ok(Time::new(1, 23, 45)));
+    /// assert_eq!(Time::parse_compound("2h 30m"), Ok(Time::new(2, 30, 0)));
+    /// assert!(Time::parse_compound("1h1x").is_err());
+    /// ```
+    pub fn parse_compound(s: &str) -> Result<Time, TimeError> {
+        let mut rest = s.trim_ascii();
+        if rest.is_empty() {
+            return Err(ParseTimeError::Empty.into());
+        }
+        let invalid = || TimeError::from(ParseTimeError::InvalidField(s.to_string()));
+        let mut seconds: u64 = 0;
+        let mut next = 0;
+        while !rest.is_empty() {
+            let end = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .ok_or_else(invalid)?;
+            let (index, scale) = match rest.as_bytes()[end] {
+                b'h' => (0, 3600),
+                b'm' => (1, 60),
+                b's' => (2, 1),
+                _ => return Err(invalid()),
+            };
+            if index < next {
+                return Err(invalid());
+            }
+            let value = parse_digits(&rest[..end]).ok_or_else(invalid)?;
+            seconds = value
+                .checked_mul(scale)
+                .and_then(|v| seconds.checked_add(v))
+                .ok_or(TimeError::Overflow)?;
+            next = index + 1;
+            rest = rest[end + 1..].trim_ascii_start();
+        }
+        Ok(Time::from_seconds(seconds))
+    }
+
+    /// Parses an ISO 8601 duration of the form `P[nD][T[nH][nM][nS]]`.
+    ///
+    /// Components may be left out but must appear in that order, and at least
+    /// one must be present. Years, months, weeks and fractional values aren't
+    /// supported. Surrounding ASCII whitespace is ignored.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// assert_eq!(Time::from_iso8601("PT1H23M45S"), Ok(Time::new(1, 23, 45)));
+    /// assert_eq!(Time::from_iso8601("PT45S"), Ok(Time::new(0, 0, 45)));
+    /// assert!(Time::from_iso8601("1H23M").is_err());
+    /// ```
+    pub fn from_iso8601(s: &str) -> Result<Time, TimeError> {
+        let s = s.trim_ascii();
+        if s.is_empty() {
+            return Err(ParseTimeError::Empty.into());
+        }
+        let invalid = || TimeError::from(ParseTimeError::InvalidField(s.to_string()));
+        let rest = s.strip_prefix('P').ok_or_else(invalid)?;
+        let (date, time) = match rest.split_once('T') {
+            Some((_, "")) => return Err(invalid()),
+            Some((date, time)) => (date, time),
+            None => (rest, ""),
+        };
+        if date.is_empty() && time.is_empty() {
+            return Err(invalid());
+        }
+        let day = match date {
+            "" => 0,
+            _ => date
+                .strip_suffix('D')
+                .and_then(parse_digits)
+                .ok_or_else(invalid)?,
+        };
+        // Each designator may appear once, in `H`, `M`, `S` order.
+        let mut fields = [0; 3];
+        let mut next = 0;
+        let mut digits = time;
+        while !digits.is_empty() {
+            let end = digits
+                .find(|c: char| !c.is_ascii_digit())
+                .ok_or_else(invalid)?;
+            let index = match digits.as_bytes()[end] {
+                b'H' => 0,
+                b'M' => 1,
+                b'S' => 2,
+                _ => return Err(invalid()),
+            };
+            if index < next {
+                return Err(invalid());
+            }
+            fields[index] = parse_digits(&digits[..end]).ok_or_else(invalid)?;
+            next = index + 1;
+            digits = &digits[end + 1..];
+        }
+        Time::checked_from_parts(day, fields[0], fields[1], fields[2]).ok_or(TimeError::Overflow)
+    }
+}
+
+// Parses a non-empty run of ASCII digits, rejecting signs and whitespace.
+fn parse_digits(s: &str) -> Option<u64> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+// Parses digits with at most one `.`, such as `1.5`, `90` or `.25`.
+fn parse_decimal(s: &str) -> Option<f64> {
+    let (whole, fraction) = s.split_once('.').unwrap_or((s, ""));
+    let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if (whole.is_empty() && fraction.is_empty()) || !is_digits(whole) || !is_digits(fraction) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::fixture;
+    use crate::{ParseTimeError, Time, TimeError};
+    use rstest::*;
+    #[rstest]
+    fn test_parse_flexible(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        assert_eq!(Time::parse_flexible("90"), Ok(Time::new(0, 1, 30)));
+        assert_eq!(Time::parse_flexible("0"), Ok(b));
+        assert_eq!(Time::parse_flexible("5:00"), Ok(Time::new(0, 5, 0)));
+        assert_eq!(Time::parse_flexible("83:45"), Ok(a));
+        assert_eq!(Time::parse_flexible("01:23:45"), Ok(a));
+        assert_eq!(Time::parse_flexible("0:0:5025"), Ok(a));
+        assert_eq!(Time::parse_flexible(" 90\n"), Ok(Time::new(0, 1, 30)));
+        assert_eq!(Time::parse_flexible("\t83:45 "), Ok(a));
+        assert_eq!(
+            Time::parse_flexible(" "),
+            Err(TimeError::Parse(ParseTimeError::Empty))
+        );
+        assert_eq!(
+            Time::parse_flexible("1 : 30"),
+            Err(TimeError::Parse(ParseTimeError::InvalidField(
+                "1 ".to_string()
+            )))
+        );
+    }
+    #[rstest]
+    fn test_parse_flexible_error() {
+        assert_eq!(
+            Time::parse_flexible(""),
+            Err(TimeError::Parse(ParseTimeError::Empty))
+        );
+        assert_eq!(
+            Time::parse_flexible("1:2:3:4"),
+            Err(TimeError::Parse(ParseTimeError::FieldCount(4)))
+        );
+        assert_eq!(
+            Time::parse_flexible("1:x"),
+            Err(TimeError::Parse(ParseTimeError::InvalidField(
+                "x".to_string()
+            )))
+        );
+        assert_eq!(
+            Time::parse_flexible("5:"),
+            Err(TimeError::Parse(ParseTimeError::InvalidField(
+                "".to_string()
+            )))
+        );
+        assert_eq!(
+            Time::parse_flexible("18446744073709551615:0:0"),
+            Err(TimeError::Overflow)
+        );
+        assert_eq!(
+            Time::parse_flexible("307445734561825860:16"),
+            Err(TimeError::Overflow)
+        );
+        assert_eq!(Time::parse_flexible("18446744073709551615"), Ok(Time::MAX));
+    }
+    #[rstest]
+    fn test_from_iso8601(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        assert_eq!(Time::from_iso8601("PT1H23M45S"), Ok(a));
+        assert_eq!(Time::from_iso8601("PT0S"), Ok(b));
+        assert_eq!(Time::from_iso8601("PT45S"), Ok(Time::new(0, 0, 45)));
+        assert_eq!(Time::from_iso8601("PT2H"), Ok(Time::new(2, 0, 0)));
+        assert_eq!(Time::from_iso8601("PT90M"), Ok(Time::new(1, 30, 0)));
+        assert_eq!(Time::from_iso8601("P2D"), Ok(Time::new(48, 0, 0)));
+        assert_eq!(Time::from_iso8601("P1DT1S"), Ok(Time::new(24, 0, 1)));
+        assert_eq!(Time::from_iso8601(" PT1H23M45S\r\n"), Ok(a));
+        assert_eq!(
+            Time::from_iso8601("\n"),
+            Err(TimeError::Parse(ParseTimeError::Empty))
+        );
+    }
+    #[rstest]
+    fn test_iso8601_round_trip(fixture: [Time; 2]) {
+        for t in fixture
+            .into_iter()
+            .chain([Time::new(49, 0, 7), Time::new(0, 5, 0)])
+        {
+            assert_eq!(Time::from_iso8601(&t.to_iso8601()), Ok(t));
+        }
+    }
+    #[rstest]
+    fn test_from_iso8601_error() {
+        assert_eq!(
+            Time::from_iso8601(""),
+            Err(TimeError::Parse(ParseTimeError::Empty))
+        );
+        for s in [
+            "P", "PT", "P1DT", "1H", "T1H", "PT1H1H", "PT1S1M", "PT1X", "PT1", "PTH", "PT-1S",
+            "PT1.5S", "P1Y", "P1WT1H", "pt1h", "PT1é",
+        ] {
+            assert_eq!(
+                Time::from_iso8601(s),
+                Err(TimeError::Parse(ParseTimeError::InvalidField(
+                    s.to_string()
+                ))),
+                "{}",
+                s
+            );
+        }
+        assert_eq!(
+            Time::from_iso8601("PT18446744073709551615H"),
+            Err(TimeError::Overflow)
+        );
+        assert_eq!(
+            Time::from_iso8601("P213503982334602D"),
+            Err(TimeError::Overflow)
+        );
+        assert_eq!(Time::from_iso8601("PT18446744073709551615S"), Ok(Time::MAX));
+    }
+    #[rstest]
+    fn test_parse_with_sep(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        assert_eq!(Time::parse_with_sep("01.23.45", '.'), Ok(a));
+        assert_eq!(Time::parse_with_sep("01-23-45", '-'), Ok(a));
+        assert_eq!(Time::parse_with_sep("00:00:5025", ':'), Ok(a));
+        for t in [a, b, Time::new(50, 0, 0)] {
+            for sep in ['.', '-', ':'] {
+                assert_eq!(Time::parse_with_sep(&t.to_string_with_sep(sep), sep), Ok(t));
+            }
+        }
+        assert_eq!(
+            Time::parse_with_sep("01:23:45", '.'),
+            Err(TimeError::Parse(ParseTimeError::FieldCount(1)))
+        );
+        assert_eq!(
+            Time::parse_with_sep("01:23:45", '.')
+                .unwrap_err()
+                .to_string(),
+            "unexpected number of time fields: 1".to_string()
+        );
+        assert_eq!(
+            Time::parse_with_sep("01.23-45", '.'),
+            Err(TimeError::Parse(ParseTimeError::FieldCount(2)))
+        );
+        assert_eq!(
+            Time::parse_with_sep("01.23.4-5", '.'),
+            Err(TimeError::Parse(ParseTimeError::InvalidField(
+                "4-5".to_string()
+            )))
+        );
+        assert_eq!(
+            Time::parse_with_sep("", '.'),
+            Err(TimeError::Parse(ParseTimeError::Empty))
+        );
+        assert_eq!(
+            Time::parse_with_sep("18446744073709551615.00.00", '.'),
+            Err(TimeError::Overflow)
+        );
+    }
+    #[rstest]
+    fn test_parse_unit(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        assert_eq!(Time::parse_unit("1.5h"), Ok(Time::new(1, 30, 0)));
+        assert_eq!(Time::parse_unit("90m"), Ok(Time::new(1, 30, 0)));
+        assert_eq!(Time::parse_unit("45s"), Ok(Time::new(0, 0, 45)));
+        assert_eq!(Time::parse_unit("5025s"), Ok(a));
+        assert_eq!(Time::parse_unit("0h"), Ok(b));
+        assert_eq!(Time::parse_unit(".25m"), Ok(Time::new(0, 0, 15)));
+        assert_eq!(Time::parse_unit("2.m"), Ok(Time::new(0, 2, 0)));
+        assert_eq!(Time::parse_unit("0.0125h"), Ok(Time::new(0, 0, 45)));
+        assert_eq!(Time::parse_unit("1.5s"), Ok(Time::new(0, 0, 2)));
+        assert_eq!(Time::parse_unit("1.4s"), Ok(Time::new(0, 0, 1)));
+        assert_eq!(Time::parse_unit("48h"), Ok(Time::new(48, 0, 0)));
+        assert_eq!(Time::parse_unit(" 1.5h\n"), Ok(Time::new(1, 30, 0)));
+        assert_eq!(
+            Time::parse_unit(" \t"),
+            Err(TimeError::Parse(ParseTimeError::Empty))
+        );
+    }
+    #[rstest]
+    fn test_parse_unit_error() {
+        assert_eq!(
+            Time::parse_unit(""),
+            Err(TimeError::Parse(ParseTimeError::Empty))
+        );
+        for s in [
+            "1.5", "1.5d", "h", ".h", "1..5h", "1.2.3h", "-1h", "+1h", "1e3s", "infs", "nanh",
+            "1 h", "1H", "1é",
+        ] {
+            assert_eq!(
+                Time::parse_unit(s),
+                Err(TimeError::Parse(ParseTimeError::InvalidField(
+                    s.to_string()
+                ))),
+                "{}",
+                s
+            );
+        }
+        assert_eq!(
+            Time::parse_unit("99999999999999999999h"),
+            Err(TimeError::Overflow)
+        );
+    }
+    #[rstest]
+    fn test_parse_compound(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        assert_eq!(Time::parse_compound("1h23m45s"), Ok(a));
+        assert_eq!(Time::parse_compound("2h30m"), Ok(Time::new(2, 30, 0)));
+        assert_eq!(Time::parse_compound("45s"), Ok(Time::new(0, 0, 45)));
+        assert_eq!(Time::parse_compound("1h45s"), Ok(Time::new(1, 0, 45)));
+        assert_eq!(Time::parse_compound("90m"), Ok(Time::new(1, 30, 0)));
+        assert_eq!(Time::parse_compound("0s"), Ok(b));
+        assert_eq!(Time::parse_compound("1h 23m  45s"), Ok(a));
+        assert_eq!(Time::parse_compound(" 1h23m45s\n"), Ok(a));
+        assert_eq!(Time::parse_compound("25h"), Ok(Time::new(25, 0, 0)));
+    }
+    #[rstest]
+    fn test_parse_compound_error() {
+        assert_eq!(
+            Time::parse_compound(" "),
+            Err(TimeError::Parse(ParseTimeError::Empty))
+        );
+        for s in [
+            "1h1x", "1d", "1h1h", "5m1h", "1s1m", "h", "1", "1h2", "1 h", "-1h", "1.5h", "1H",
+            "1h,2m", "1hé",
+        ] {
+            assert_eq!(
+                Time::parse_compound(s),
+                Err(TimeError::Parse(ParseTimeError::InvalidField(
+                    s.to_string()
+                ))),
+                "{}",
+                s
+            );
+        }
+        assert_eq!(
+            Time::parse_compound("5124095576030431h15s"),
+            Ok(Time::from_seconds(u64::MAX))
+        );
+        assert_eq!(
+            Time::parse_compound("5124095576030431h1m"),
+            Err(TimeError::Overflow)
+        );
+        assert_eq!(
+            Time::parse_compound("6000000000000000h"),
+            Err(TimeError::Overflow)
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use alloc::string::String;
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+use crate::Time;
+
+/// Serializes as the `Display` string, e.g. `"01:23:45"`, in human-readable
+/// formats such as JSON.
+///
+/// Binary formats such as bincode get the total seconds as a single `u64`
+/// instead, which is smaller and exact.
+impl Serialize for Time {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_u64(self.total_seconds())
+        }
+    }
+}
+
+/// Deserializes from the string form accepted by `FromStr`, or from total
+/// seconds in binary formats, normalizing the result.
+impl<'de> Deserialize<'de> for Time {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            s.parse().map_err(de::Error::custom)
+        } else {
+            u64::deserialize(deserializer).map(Time::from_seconds)
+        }
+    }
+}
+
+/// Always (de)serializes as the `Display` string, even in binary formats.
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use time_calculator::Time;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Lap {
+///     #[serde(with = "time_calculator::serde_as_string")]
+///     time: Time,
+/// }
+///
+/// let lap = Lap { time: Time::new(1, 23, 45) };
+/// assert_eq!(serde_json::to_string(&lap).unwrap(), r#"{"time":"01:23:45"}"#);
+/// ```
+pub mod serde_as_string {
+    use alloc::string::String;
+    use serde::de::{self, Deserialize, Deserializer};
+    use serde::ser::Serializer;
+
+    use crate::Time;
+
+    pub fn serialize<S: Serializer>(time: &Time, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(time)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Time, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+/// (De)serializes as the total number of seconds, e.g. `5025`.
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use time_calculator::Time;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Lap {
+///     #[serde(with = "time_calculator::serde_as_seconds")]
+///     time: Time,
+/// }
+///
+/// let lap = Lap { time: Time::new(1, 23, 45) };
+/// assert_eq!(serde_json::to_string(&lap).unwrap(), r#"{"time":5025}"#);
+/// ```
+pub mod serde_as_seconds {
+    use serde::de::{Deserialize, Deserializer};
+    use serde::ser::Serializer;
+
+    use crate::Time;
+
+    pub fn serialize<S: Serializer>(time: &Time, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(time.total_seconds())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Time, D::Error> {
+        u64::deserialize(deserializer).map(Time::from_seconds)
+    }
+}
+
+/// (De)serializes as a struct with `hour`, `minute` and `second` fields.
+///
+/// Whole days are folded into `hour`, as with [`Time::to_hms`]. Fields that
+/// overflow when deserializing are carried upwards.
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use time_calculator::Time;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Lap {
+///     #[serde(with = "time_calculator::serde_as_hms_struct")]
+///     time: Time,
+/// }
+///
+/// let lap = Lap { time: Time::new(1, 23, 45) };
+/// assert_eq!(
+///     serde_json::to_string(&lap).unwrap(),
+///     r#"{"time":{"hour":1,"minute":23,"second":45}}"#
+/// );
+/// ```
+pub mod serde_as_hms_struct {
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{ParseTimeError, Time};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "Time")]
+    struct Hms {
+        hour: u64,
+        minute: u64,
+        second: u64,
+    }
+
+    pub fn serialize<S: Serializer>(time: &Time, serializer: S) -> Result<S::Ok, S::Error> {
+        let (hour, minute, second) = time.to_hms();
+        Hms {
+            hour,
+            minute,
+            second,
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Time, D::Error> {
+        let hms = Hms::deserialize(deserializer)?;
+        Time::checked_from_parts(0, hms.hour, hms.minute, hms.second)
+            .ok_or_else(|| de::Error::custom(ParseTimeError::Overflow))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Time;
+    use rstest::*;
+    use serde::{Deserialize, Serialize};
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Laps {
+        #[serde(with = "crate::serde_as_string")]
+        string: Time,
+        #[serde(with = "crate::serde_as_seconds")]
+        seconds: Time,
+        #[serde(with = "crate::serde_as_hms_struct")]
+        hms: Time,
+    }
+    #[rstest]
+    fn test_serialize() {
+        let a = Time::new(1, 23, 45);
+        assert_eq!(serde_json::to_string(&a).unwrap(), "\"01:23:45\"");
+        let b = Time::new(50, 0, 0);
+        assert_eq!(serde_json::to_string(&b).unwrap(), "\"2 days 02:00:00\"");
+    }
+    #[rstest]
+    fn test_deserialize() {
+        let a: Time = serde_json::from_str("\"01:23:45\"").unwrap();
+        assert_eq!(a, Time::new(1, 23, 45));
+        let b: Time = serde_json::from_str("\"00:00:5025\"").unwrap();
+        assert_eq!(b.to_string(), "01:23:45".to_string());
+        assert!(serde_json::from_str::<Time>("\"01:23\"").is_err());
+        assert!(serde_json::from_str::<Time>("5025").is_err());
+        let err = serde_json::from_str::<Time>("\"18446744073709551615:00:00\"").unwrap_err();
+        assert_eq!(err.to_string(), "time is too large to represent");
+    }
+    #[rstest]
+    fn test_round_trip() {
+        let times = vec![
+            Time::new(1, 23, 45),
+            Time::new(0, 0, 0),
+            Time::new(50, 0, 0),
+        ];
+        let json = serde_json::to_string(&times).unwrap();
+        assert_eq!(serde_json::from_str::<Vec<Time>>(&json).unwrap(), times);
+    }
+    #[rstest]
+    fn test_bincode() {
+        let a = Time::new(1, 23, 45);
+        let bytes = bincode::serialize(&a).unwrap();
+        assert_eq!(bytes, 5025u64.to_le_bytes());
+        assert_eq!(bincode::deserialize::<Time>(&bytes).unwrap(), a);
+        let times = vec![Time::new(50, 0, 0), Time::from_seconds(u64::MAX)];
+        let bytes = bincode::serialize(&times).unwrap();
+        assert_eq!(bincode::deserialize::<Vec<Time>>(&bytes).unwrap(), times);
+    }
+    #[rstest]
+    fn test_with_modules() {
+        let a = Time::new(1, 23, 45);
+        let laps = Laps {
+            string: a,
+            seconds: a,
+            hms: a,
+        };
+        let json = serde_json::to_string(&laps).unwrap();
+        assert_eq!(
+            json,
+            r#"{"string":"01:23:45","seconds":5025,"hms":{"hour":1,"minute":23,"second":45}}"#
+        );
+        assert_eq!(serde_json::from_str::<Laps>(&json).unwrap(), laps);
+        let carried: Laps = serde_json::from_str(
+            r#"{"string":"00:00:90","seconds":90,"hms":{"hour":0,"minute":0,"second":90}}"#,
+        )
+        .unwrap();
+        let b = Time::new(0, 1, 30);
+        assert_eq!(
+            carried,
+            Laps {
+                string: b,
+                seconds: b,
+                hms: b
+            }
+        );
+        let long = Time::new(50, 0, 0);
+        let json = serde_json::to_value(Laps {
+            string: long,
+            seconds: long,
+            hms: long,
+        })
+        .unwrap();
+        assert_eq!(json["string"], "2 days 02:00:00");
+        assert_eq!(json["seconds"], 180000);
+        assert_eq!(json["hms"]["hour"], 50);
+        assert!(serde_json::from_str::<Laps>(
+            r#"{"string":5025,"seconds":5025,"hms":{"hour":1,"minute":23,"second":45}}"#
+        )
+        .is_err());
+        let err = serde_json::from_str::<Laps>(
+            r#"{"string":"00:00:00","seconds":0,"hms":{"hour":18446744073709551615,"minute":0,"second":0}}"#,
+        )
+        .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("time is too large to represent"));
+    }
+    #[rstest]
+    fn test_with_modules_bincode() {
+        let a = Time::new(1, 23, 45);
+        let laps = Laps {
+            string: a,
+            seconds: a,
+            hms: a,
+        };
+        let bytes = bincode::serialize(&laps).unwrap();
+        assert_eq!(bincode::deserialize::<Laps>(&bytes).unwrap(), laps);
+    }
+}
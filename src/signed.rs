@@ -0,0 +1,316 @@
+use alloc::format;
+use alloc::string::ToString;
+use core::fmt::{self, Display, Formatter};
+use core::ops::{Add, Neg, Sub};
+use core::str::FromStr;
+
+use crate::{ParseTimeError, Time};
+
+/// A signed span of time, for deltas that may run backwards.
+///
+/// The value is stored as a signed count of seconds. It renders in
+/// sign-magnitude form: the `HH:MM:SS` part is the normalized absolute value,
+/// prefixed with `-` when negative, so minus forty minutes is `-00:40:00`.
+///
+/// ```
+/// use time_calculator::Time;
+///
+/// let delta = Time::new(0, 20, 0).signed_sub(Time::new(1, 0, 0));
+/// assert_eq!(delta.to_string(), "-00:40:00");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct SignedTime {
+    seconds: i64,
+}
+
+impl SignedTime {
+    /// Creates a `SignedTime` from a signed number of seconds.
+    pub fn from_secs(seconds: i64) -> Self {
+        SignedTime { seconds }
+    }
+
+    /// Returns the signed number of seconds.
+    pub fn as_secs(&self) -> i64 {
+        self.seconds
+    }
+
+    /// Returns `true` if the span runs backwards.
+    pub fn is_negative(&self) -> bool {
+        self.seconds < 0
+    }
+
+    /// Returns the absolute value as an unsigned [`Time`].
+    pub fn magnitude(&self) -> Time {
+        Time::from_seconds(self.seconds.unsigned_abs())
+    }
+}
+
+// Panics rather than silently wrapping when a `Time` exceeds `i64::MAX` seconds.
+fn signed_seconds(time: Time) -> i64 {
+    i64::try_from(time.total_seconds()).expect("`Time` is too large for `SignedTime`")
+}
+
+impl Time {
+    /// Subtracts `other` without saturating, returning a possibly negative delta.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either time exceeds `i64::MAX` seconds.
+    pub fn signed_sub(self, other: Time) -> SignedTime {
+        SignedTime::from_secs(signed_seconds(self) - signed_seconds(other))
+    }
+
+    /// Returns how much time passed from `earlier` to `self`, negative if
+    /// `earlier` is actually the later of the two.
+    ///
+    /// This is [`Time::signed_sub`] read in timeline order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either time exceeds `i64::MAX` seconds.
+    ///
+    /// ```
+    /// use time_calculator::Time;
+    ///
+    /// let (start, end) = (Time::new(0, 30, 0), Time::new(1, 0, 0));
+    /// assert_eq!(end.elapsed_since(start).to_string(), "00:30:00");
+    /// assert_eq!(start.elapsed_since(end).to_string(), "-00:30:00");
+    /// ```
+    pub fn elapsed_since(&self, earlier: Time) -> SignedTime {
+        self.signed_sub(earlier)
+    }
+}
+
+/// Width, fill, alignment and the alternate form behave as for [`Time`],
+/// with the sign counted as part of the padded text.
+impl Display for SignedTime {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let sign = if self.is_negative() { "-" } else { "" };
+        let magnitude = if f.alternate() {
+            format!("{:#}", self.magnitude())
+        } else {
+            self.magnitude().to_string()
+        };
+        f.pad(&format!("{}{}", sign, magnitude))
+    }
+}
+
+impl FromStr for SignedTime {
+    type Err = ParseTimeError;
+
+    /// Parses an optional `-` or `+` sign followed by the form accepted by
+    /// [`Time`]'s `FromStr`, such as `-01:23:45`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim_ascii();
+        if s.is_empty() {
+            return Err(ParseTimeError::Empty);
+        }
+        let invalid = || ParseTimeError::InvalidField(s.to_string());
+        let (negative, magnitude) = match s.as_bytes()[0] {
+            b'-' => (true, &s[1..]),
+            b'+' => (false, &s[1..]),
+            _ => (false, s),
+        };
+        if magnitude.is_empty() || magnitude.trim_ascii_start().len() != magnitude.len() {
+            return Err(invalid());
+        }
+        let seconds = i64::try_from(magnitude.parse::<Time>()?.total_seconds())
+            .map_err(|_| ParseTimeError::Overflow)?;
+        Ok(SignedTime::from_secs(if negative {
+            -seconds
+        } else {
+            seconds
+        }))
+    }
+}
+
+/// Flips the direction of the span.
+///
+/// # Panics
+///
+/// Panics on `SignedTime::from_secs(i64::MIN)`, whose magnitude has no
+/// positive `i64` counterpart.
+impl Neg for SignedTime {
+    type Output = Self;
+    fn neg(self) -> SignedTime {
+        SignedTime::from_secs(
+            self.seconds
+                .checked_neg()
+                .expect("`SignedTime` negation overflowed"),
+        )
+    }
+}
+
+impl Add for SignedTime {
+    type Output = Self;
+    fn add(self, other: Self) -> SignedTime {
+        SignedTime::from_secs(self.seconds + other.seconds)
+    }
+}
+
+impl Sub for SignedTime {
+    type Output = Self;
+    fn sub(self, other: Self) -> SignedTime {
+        SignedTime::from_secs(self.seconds - other.seconds)
+    }
+}
+
+impl Add<Time> for SignedTime {
+    type Output = SignedTime;
+    fn add(self, other: Time) -> SignedTime {
+        SignedTime::from_secs(self.seconds + signed_seconds(other))
+    }
+}
+
+impl Sub<Time> for SignedTime {
+    type Output = SignedTime;
+    fn sub(self, other: Time) -> SignedTime {
+        SignedTime::from_secs(self.seconds - signed_seconds(other))
+    }
+}
+
+impl Add<SignedTime> for Time {
+    type Output = SignedTime;
+    fn add(self, other: SignedTime) -> SignedTime {
+        SignedTime::from_secs(signed_seconds(self) + other.seconds)
+    }
+}
+
+impl Sub<SignedTime> for Time {
+    type Output = SignedTime;
+    fn sub(self, other: SignedTime) -> SignedTime {
+        SignedTime::from_secs(signed_seconds(self) - other.seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ParseTimeError, SignedTime, Time};
+    use rstest::*;
+    #[fixture]
+    pub fn fixture() -> [Time; 2] {
+        [Time::new(0, 20, 0), Time::new(1, 0, 0)]
+    }
+    #[rstest]
+    fn test_signed_sub(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        let delta = a.signed_sub(b);
+        assert_eq!(delta.as_secs(), -2400);
+        assert!(delta.is_negative());
+        assert_eq!(delta.magnitude(), Time::new(0, 40, 0));
+        assert_eq!(b.signed_sub(a), SignedTime::from_secs(2400));
+        assert_eq!(a.signed_sub(a), SignedTime::default());
+    }
+    #[rstest]
+    fn test_elapsed_since(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        let later = Time::new(1, 0, 0);
+        let earlier = Time::new(0, 30, 0);
+        assert_eq!(later.elapsed_since(earlier), SignedTime::from_secs(1800));
+        assert_eq!(earlier.elapsed_since(later), SignedTime::from_secs(-1800));
+        assert!(earlier.elapsed_since(later).is_negative());
+        assert_eq!(b.elapsed_since(a), b.signed_sub(a));
+        assert_eq!(a.elapsed_since(a), SignedTime::default());
+    }
+    #[rstest]
+    fn test_str(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        assert_eq!(a.signed_sub(b).to_string(), "-00:40:00".to_string());
+        assert_eq!(b.signed_sub(a).to_string(), "00:40:00".to_string());
+        assert_eq!(
+            SignedTime::from_secs(-90000).to_string(),
+            "-1 day 01:00:00".to_string()
+        );
+        assert_eq!(SignedTime::from_secs(0).to_string(), "00:00:00".to_string());
+        assert_eq!(
+            format!("{:>12}", SignedTime::from_secs(-60)),
+            "   -00:01:00"
+        );
+        assert_eq!(format!("{:<10}|", b.signed_sub(a)), "00:40:00  |");
+        assert_eq!(format!("{:#}", SignedTime::from_secs(-3600)), "-1:00:00");
+    }
+    #[rstest]
+    fn test_from_str(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        let delta = a.signed_sub(b);
+        assert_eq!("-00:40:00".parse::<SignedTime>(), Ok(delta));
+        assert_eq!("+00:40:00".parse::<SignedTime>(), Ok(-delta));
+        assert_eq!("00:40:00".parse::<SignedTime>(), Ok(-delta));
+        assert_eq!(
+            "-01:23:45".parse::<SignedTime>().unwrap().to_string(),
+            "-01:23:45".to_string()
+        );
+        assert_eq!(
+            "-1 day 01:00:00".parse::<SignedTime>(),
+            Ok(SignedTime::from_secs(-90000))
+        );
+        assert_eq!(
+            " -00:00:01\n".parse::<SignedTime>(),
+            Ok(SignedTime::from_secs(-1))
+        );
+        assert_eq!("-00:00:00".parse::<SignedTime>(), Ok(SignedTime::default()));
+        for t in [delta, -delta, SignedTime::from_secs(-90000)] {
+            assert_eq!(t.to_string().parse::<SignedTime>(), Ok(t));
+        }
+    }
+    #[rstest]
+    fn test_from_str_error() {
+        assert_eq!("".parse::<SignedTime>(), Err(ParseTimeError::Empty));
+        for s in ["-", "+", "--01:00:00", "+-01:00:00", "- 01:00:00"] {
+            let err = s.parse::<SignedTime>().unwrap_err();
+            assert!(matches!(err, ParseTimeError::InvalidField(_)), "{}", s);
+        }
+        assert_eq!(
+            "-01:23".parse::<SignedTime>(),
+            Err(ParseTimeError::FieldCount(2))
+        );
+        assert_eq!(
+            "-18446744073709551615:00:00".parse::<SignedTime>(),
+            Err(ParseTimeError::Overflow)
+        );
+        assert_eq!(
+            "-0:0:18446744073709551615".parse::<SignedTime>(),
+            Err(ParseTimeError::Overflow)
+        );
+        assert_eq!(
+            "-0:0:9223372036854775807".parse::<SignedTime>(),
+            Ok(SignedTime::from_secs(-i64::MAX))
+        );
+    }
+    #[rstest]
+    fn test_neg(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        let delta = a.signed_sub(b);
+        assert_eq!(-delta, b.signed_sub(a));
+        assert_eq!(
+            -(-(SignedTime::from_secs(-100))),
+            SignedTime::from_secs(-100)
+        );
+        assert_eq!(
+            (-SignedTime::from_secs(100)).to_string(),
+            "-00:01:40".to_string()
+        );
+        assert_eq!(-SignedTime::default(), SignedTime::default());
+        assert_eq!(
+            -SignedTime::from_secs(i64::MAX),
+            SignedTime::from_secs(i64::MIN + 1)
+        );
+    }
+    #[rstest]
+    #[should_panic(expected = "`SignedTime` negation overflowed")]
+    fn test_neg_overflow() {
+        let _ = -SignedTime::from_secs(i64::MIN);
+    }
+    #[rstest]
+    fn test_add_sub(fixture: [Time; 2]) {
+        let [a, b] = fixture;
+        let delta = a.signed_sub(b);
+        assert_eq!(a - delta, SignedTime::from_secs(3600));
+        assert_eq!(b + delta, SignedTime::from_secs(1200));
+        assert_eq!(delta + b, SignedTime::from_secs(1200));
+        assert_eq!(delta - a, SignedTime::from_secs(-3600));
+        assert_eq!(delta + delta, SignedTime::from_secs(-4800));
+        assert_eq!(delta - delta, SignedTime::default());
+        assert!(delta < SignedTime::default());
+    }
+}
@@ -0,0 +1,112 @@
+use std::time::{Duration, Instant};
+
+use crate::Time;
+
+/// Measures elapsed wall time as a [`Time`].
+///
+/// ```
+/// use time_calculator::{Stopwatch, Time};
+///
+/// let watch = Stopwatch::start();
+/// assert!(watch.elapsed() < Time::new(1, 0, 0));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Stopwatch {
+    started: Instant,
+}
+
+impl Stopwatch {
+    /// Starts a new stopwatch running from now.
+    pub fn start() -> Self {
+        Stopwatch {
+            started: Instant::now(),
+        }
+    }
+
+    /// Returns the time since the stopwatch was started or last reset,
+    /// truncated to whole seconds.
+    pub fn elapsed(&self) -> Time {
+        Time::from(self.started.elapsed())
+    }
+
+    /// Restarts the measurement from now.
+    pub fn reset(&mut self) {
+        self.started = Instant::now();
+    }
+}
+
+/// Counts down from a [`Time`] deadline measured against an [`Instant`].
+///
+/// ```
+/// use time_calculator::{Time, Timer};
+///
+/// let timer = Timer::start(Time::new(1, 0, 0));
+/// assert!(!timer.is_expired());
+/// assert!(timer.remaining() <= Time::new(1, 0, 0));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Timer {
+    deadline: Time,
+    started: Instant,
+}
+
+impl Timer {
+    /// Creates a timer that expires `deadline` after `started`.
+    pub fn new(deadline: Time, started: Instant) -> Self {
+        Timer { deadline, started }
+    }
+
+    /// Starts a timer that expires `deadline` from now.
+    pub fn start(deadline: Time) -> Self {
+        Timer::new(deadline, Instant::now())
+    }
+
+    /// Returns the time left before the deadline, rounded up to whole
+    /// seconds so that it only reads zero once the timer has expired.
+    pub fn remaining(&self) -> Time {
+        let left = Duration::from(self.deadline).saturating_sub(self.started.elapsed());
+        Time::from_seconds(left.as_secs() + u64::from(left.subsec_nanos() > 0))
+    }
+
+    /// Returns `true` once the deadline has been reached.
+    pub fn is_expired(&self) -> bool {
+        self.remaining().is_zero()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Stopwatch, Time, Timer};
+    use rstest::*;
+    use std::thread;
+    use std::time::{Duration, Instant};
+    #[rstest]
+    fn test_elapsed() {
+        let mut watch = Stopwatch::start();
+        assert!(watch.elapsed() < Time::new(0, 0, 1));
+        thread::sleep(Duration::from_millis(1100));
+        let elapsed = watch.elapsed();
+        assert!(!elapsed.is_zero());
+        assert!(elapsed < Time::new(0, 1, 0));
+        watch.reset();
+        assert!(watch.elapsed() < elapsed);
+    }
+    #[rstest]
+    fn test_timer() {
+        let timer = Timer::start(Time::new(0, 0, 1));
+        assert!(!timer.is_expired());
+        assert_eq!(timer.remaining(), Time::SECOND);
+        thread::sleep(Duration::from_millis(1100));
+        assert!(timer.is_expired());
+        assert_eq!(timer.remaining(), Time::ZERO);
+        assert!(Timer::start(Time::ZERO).is_expired());
+        let started = Instant::now() - Duration::from_secs(90);
+        let timer = Timer::new(Time::new(0, 2, 0), started);
+        assert!(!timer.is_expired());
+        assert_eq!(timer.remaining(), Time::new(0, 0, 30));
+        let started = Instant::now() - Duration::from_millis(90_500);
+        let timer = Timer::new(Time::new(0, 1, 31), started);
+        assert!(!timer.is_expired());
+        assert_eq!(timer.remaining(), Time::SECOND);
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{Time, TimeError};
+
+/// Converts through whole seconds.
+///
+/// # Panics
+///
+/// Panics if the time exceeds `i64::MAX` seconds.
+impl From<Time> for time::Duration {
+    fn from(t: Time) -> Self {
+        time::Duration::seconds(
+            i64::try_from(t.total_seconds()).expect("`Time` is too large for `time::Duration`"),
+        )
+    }
+}
+
+/// Fails with [`TimeError::Negative`] for negative durations. The sub-second
+/// part is truncated.
+impl TryFrom<time::Duration> for Time {
+    type Error = TimeError;
+    fn try_from(duration: time::Duration) -> Result<Self, Self::Error> {
+        if duration.is_negative() {
+            return Err(TimeError::Negative);
+        }
+        Ok(Time::from_seconds(duration.whole_seconds() as u64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::fixture;
+    use crate::{Time, TimeError};
+    use rstest::*;
+    use time::Duration;
+    #[rstest]
+    fn test_into_time_duration(fixture: [Time; 2]) {
+        assert_eq!(Duration::from(fixture[0]), Duration::seconds(5025));
+        assert_eq!(Duration::from(fixture[1]), Duration::ZERO);
+        assert_eq!(Duration::from(Time::new(50, 0, 0)), Duration::hours(50));
+    }
+    #[rstest]
+    fn test_from_time_duration(fixture: [Time; 2]) {
+        assert_eq!(Time::try_from(Duration::seconds(5025)), Ok(fixture[0]));
+        assert_eq!(Time::try_from(Duration::ZERO), Ok(fixture[1]));
+        assert_eq!(
+            Time::try_from(Duration::milliseconds(5025999)),
+            Ok(fixture[0])
+        );
+        assert_eq!(
+            Time::try_from(Duration::seconds(-1)),
+            Err(TimeError::Negative)
+        );
+        assert_eq!(
+            Time::try_from(Duration::milliseconds(-1)),
+            Err(TimeError::Negative)
+        );
+    }
+    #[rstest]
+    fn test_round_trip(fixture: [Time; 2]) {
+        for t in fixture.into_iter().chain([Time::new(49, 0, 7)]) {
+            assert_eq!(Time::try_from(Duration::from(t)), Ok(t));
+        }
+        let max = Time::from_seconds(i64::MAX as u64);
+        assert_eq!(Time::try_from(Duration::from(max)), Ok(max));
+    }
+    #[rstest]
+    #[should_panic]
+    fn test_too_large() {
+        let _ = Duration::from(Time::MAX);
+    }
+}